@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use config::{Config, File};
 use reqwest::Url;
 use serde::Deserialize;
@@ -7,7 +9,20 @@ pub struct Settings {
     pub application: ApplicationSettings,
     pub poke_api: PokeApi,
     pub funtranslation_api: FuntranslationApi,
+    pub cache: CacheSettings,
+    pub memory_cache: MemoryCache,
     pub redis_cache: RedisCache,
+    pub language: LanguageSettings,
+    pub translation: TranslationSettings,
+    pub batch: BatchSettings,
+    pub retry: RetrySettings,
+    pub circuit_breaker: CircuitBreakerSettings,
+    pub pokemon_cache: PokemonCacheSettings,
+    pub http_cache: HttpCacheSettings,
+    pub species_names: SpeciesNamesSettings,
+    pub listing: ListingSettings,
+    #[serde(default)]
+    pub telemetry: TelemetrySettings,
 }
 
 #[derive(Deserialize)]
@@ -26,11 +41,151 @@ pub struct PokeApi {
 pub struct FuntranslationApi {
     pub url: Url,
     pub timeout_seconds: u64,
+    /// The FunTranslations quota: `quota_limit` requests every `quota_period_seconds`.
+    pub quota_limit: u32,
+    pub quota_period_seconds: u64,
+}
+
+#[derive(Deserialize)]
+pub struct CacheSettings {
+    pub kind: CacheKind,
+}
+
+/// The cache used for the translations:
+/// `tiered` uses the memory cache in front of Redis.
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheKind {
+    None,
+    Memory,
+    Redis,
+    Tiered,
+}
+
+#[derive(Deserialize)]
+pub struct MemoryCache {
+    pub capacity: usize,
+    pub ttl_seconds: u64,
 }
 
 #[derive(Deserialize)]
 pub struct RedisCache {
     pub url: Url,
+    /// Must be greater than zero.
+    pub ttl_seconds: usize,
+}
+
+/// Caching of the pokemon retrieved from PokeAPI, in the cache selected by `cache.kind`.
+#[derive(Deserialize)]
+pub struct PokemonCacheSettings {
+    /// `0` disables the caching.
+    pub ttl_seconds: u64,
+    /// Unknown pokemon are cached for a shorter time, in case they are added.
+    pub not_found_ttl_seconds: u64,
+}
+
+/// The `max-age` advertised to the clients and CDNs by each pokemon route.
+#[derive(Deserialize)]
+pub struct HttpCacheSettings {
+    pub pokemon_max_age_seconds: u32,
+    pub translated_max_age_seconds: u32,
+}
+
+/// The species names loaded from PokeAPI, to suggest a name for the unknown pokemon.
+#[derive(Deserialize)]
+pub struct SpeciesNamesSettings {
+    pub refresh_seconds: u64,
+    pub max_suggestions: usize,
+}
+
+/// Export of the traces to an OpenTelemetry collector, disabled without `otlp_endpoint`.
+#[derive(Deserialize, Default)]
+pub struct TelemetrySettings {
+    pub otlp_endpoint: Option<Url>,
+}
+
+/// Page sizes of the species listing.
+#[derive(Deserialize)]
+pub struct ListingSettings {
+    pub default_limit: u32,
+    pub max_limit: u32,
+}
+
+#[derive(Deserialize)]
+pub struct LanguageSettings {
+    /// Languages tried, in order, when none of the requested ones has a description.
+    pub fallback: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct TranslationSettings {
+    /// Part of every cache key: bump it to invalidate all the cached translations.
+    pub rules_version: u32,
+    /// Registry of the translation styles, each one mapped to its FunTranslations endpoint.
+    pub styles: HashMap<String, String>,
+    /// Style used when no rule matches.
+    pub default_style: String,
+    /// Ordered rules selecting the translation style: the first matching one wins.
+    #[serde(default)]
+    pub rules: Vec<TranslationRuleSettings>,
+    /// Translator serving each style: styles not listed use `funtranslation`.
+    #[serde(default)]
+    pub modes: HashMap<String, TranslationMode>,
+}
+
+/// `local` translates offline, `fallback` uses it when FunTranslations fails.
+/// Only `yoda` and `shakespeare` have a local translator.
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum TranslationMode {
+    Funtranslation,
+    Local,
+    Fallback,
+}
+
+/// A missing condition matches every pokemon.
+#[derive(Deserialize)]
+pub struct TranslationRuleSettings {
+    pub name: String,
+    pub style: String,
+    pub habitat: Option<String>,
+    pub legendary: Option<bool>,
+    pub mythical: Option<bool>,
+    #[serde(rename = "type")]
+    pub pokemon_type: Option<String>,
+    pub generation: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct BatchSettings {
+    /// Maximum number of distinct pokemon in a batch request.
+    pub max_size: usize,
+}
+
+/// Retry of the requests to PokeAPI and FunTranslations failing with a transient error.
+#[derive(Deserialize)]
+pub struct RetrySettings {
+    /// Attempts of every request, the first one included.
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+    /// Random fraction, from 0 to 1, taken off every delay.
+    pub jitter: f64,
+    pub retry_statuses: Vec<u16>,
+    /// Retries earned by every request, up to `budget_burst`.
+    pub budget_ratio: f64,
+    pub budget_burst: u32,
+}
+
+/// Circuit breaking the calls to PokeAPI and FunTranslations while they are failing.
+#[derive(Deserialize)]
+pub struct CircuitBreakerSettings {
+    /// Consecutive failures opening the circuit.
+    pub failure_threshold: u32,
+    /// Seconds the open circuit fails fast before trying a call.
+    pub open_seconds: u64,
+    /// Successful trial calls closing the circuit again.
+    pub success_threshold: u32,
 }
 
 impl Settings {
@@ -1,14 +1,31 @@
+use std::collections::HashMap;
 use std::net::TcpListener;
+use std::time::Duration;
 
-use actix_web::dev::Server;
+use actix_web::dev::{Server, Service};
 use actix_web::{web, App, HttpResponse, HttpServer};
 use anyhow::Context;
+use futures::FutureExt;
 use tracing_actix_web::TracingLogger;
 
-use crate::configuration::settings::Settings;
-use crate::pokemon_bounded_context::adapter::out::{FuntranslationApi, PokeApi, RedisCache};
+use crate::configuration::settings::{
+    CacheKind, CircuitBreakerSettings, PokemonCacheSettings, RetrySettings, Settings,
+    TranslationMode, TranslationSettings,
+};
+use crate::pokemon_bounded_context::adapter::metrics::Metrics;
+use crate::pokemon_bounded_context::adapter::out::{
+    CacheBackend, CachingPokemonRetrieval, Circuit, CircuitBreaker, FuntranslationApi,
+    LocalTranslator, MemoryCache, MeteredCache, PokeApi, RedisCache, RetryBudget, RetryPolicy,
+    TieredCache, TranslationQuota, TranslatorMode, TranslatorRouter,
+};
 use crate::pokemon_bounded_context::adapter::route;
-use crate::pokemon_bounded_context::port::service::{PokemonInfo, PokemonTranslator};
+use crate::pokemon_bounded_context::domain::{
+    Language, RuleConditions, TranslationRule, TranslationRules, TranslationStyle,
+};
+use crate::pokemon_bounded_context::port::out::Translator;
+use crate::pokemon_bounded_context::port::service::{
+    PokemonEvolutions, PokemonInfo, PokemonTranslator, SpeciesCatalog, SpeciesNames,
+};
 
 pub struct PokedexApp {
     pub server: Result<Server, anyhow::Error>,
@@ -26,34 +43,153 @@ impl PokedexApp {
             .context("Fail to extract port from binding url")?
             .port();
 
+        let cache = build_cache(&settings).await?;
+        let metrics = Metrics::new().context("Failed to register the metrics")?;
+
+        // the PokeAPI services share a single client: its connection pool, retry budget and circuit
+        let poke_api_circuit = circuit("poke_api", &settings.circuit_breaker);
+        let poke_api = CircuitBreaker::new(
+            PokeApi::new(
+                settings.poke_api.url,
+                settings.poke_api.timeout_seconds,
+                retry_policy(&settings.retry).with_metrics(metrics.upstream("poke_api")),
+            )
+            .context("Failed to instantiate `PokeApi` client")?,
+            poke_api_circuit.clone(),
+        );
+        let funtranslation_api_circuit = circuit("funtranslation_api", &settings.circuit_breaker);
+
+        let pokemon_evolutions = web::Data::new(PokemonEvolutions::new(poke_api.clone()));
+
+        let species_catalog = web::Data::new(SpeciesCatalog::new(
+            poke_api.clone(),
+            settings.listing.default_limit,
+            settings.listing.max_limit,
+        ));
+
+        let species_names = SpeciesNames::new(
+            poke_api.clone(),
+            Duration::from_secs(settings.species_names.refresh_seconds),
+            settings.species_names.max_suggestions,
+        );
+
         let pokemon_info = web::Data::new(PokemonInfo::new(
-            PokeApi::new(settings.poke_api.url, settings.poke_api.timeout_seconds)
-                .context("Failed to instantiate `PokeApi` client")?,
+            CachingPokemonRetrieval::new(
+                poke_api,
+                pokemon_cache(&settings.pokemon_cache, &cache),
+                Duration::from_secs(settings.pokemon_cache.ttl_seconds),
+                Duration::from_secs(settings.pokemon_cache.not_found_ttl_seconds),
+            ),
+            species_names,
+            settings
+                .language
+                .fallback
+                .iter()
+                .map(|language| Language::new(language))
+                .collect(),
+            settings.batch.max_size,
         ));
 
+        let translation_quota = TranslationQuota::new(
+            settings.funtranslation_api.quota_limit,
+            Duration::from_secs(settings.funtranslation_api.quota_period_seconds),
+        )
+        .with_metrics(metrics.funtranslation_quota());
+        let translator = TranslatorRouter::new(
+            CircuitBreaker::new(
+                FuntranslationApi::new(
+                    settings.funtranslation_api.url,
+                    settings.funtranslation_api.timeout_seconds,
+                    &settings.translation.styles,
+                    translation_quota.clone(),
+                    retry_policy(&settings.retry)
+                        .with_metrics(metrics.upstream("funtranslation_api")),
+                )?,
+                funtranslation_api_circuit.clone(),
+            ),
+            LocalTranslator::new(),
+            translator_modes(&settings.translation),
+        );
+        let translation_rules = translation_rules(&settings.translation);
+        if let Some(style) = translation_rules
+            .styles()
+            .chain(translator_modes(&settings.translation).keys())
+            .find(|style| !translator.supports(style))
+        {
+            anyhow::bail!("The translation style `{}` is not registered", style);
+        }
+
         let pokemon_translator = web::Data::new(PokemonTranslator::new(
-            FuntranslationApi::new(
-                settings.funtranslation_api.url.clone(),
-                settings.funtranslation_api.timeout_seconds,
-            )?,
-            FuntranslationApi::new(
-                settings.funtranslation_api.url,
-                settings.funtranslation_api.timeout_seconds,
-            )?,
-            RedisCache::new(settings.redis_cache.url.as_str()).await?,
-            RedisCache::new(settings.redis_cache.url.as_str()).await?,
+            translator,
+            MeteredCache::new(cache.clone(), metrics.translation_cache()),
+            MeteredCache::new(cache.clone(), metrics.translation_cache()),
+            translation_rules,
+            settings.translation.rules_version,
         ));
 
+        let cache = web::Data::new(cache);
+        let response_caching = web::Data::new(route::ResponseCaching::new(
+            settings.http_cache.pokemon_max_age_seconds,
+            settings.http_cache.translated_max_age_seconds,
+        ));
+        let translation_quota = web::Data::new(translation_quota);
+        let circuits = web::Data::new(vec![poke_api_circuit, funtranslation_api_circuit]);
+        let metrics = web::Data::new(metrics);
+
         let server = HttpServer::new(move || {
             App::new()
                 .route("/health_check", web::get().to(HttpResponse::Ok))
+                .route("/readiness", web::get().to(route::readiness))
+                .route("/metrics", web::get().to(route::metrics))
+                .route("/pokemon", web::get().to(route::pokemon_list))
+                .route("/pokemon/batch", web::get().to(route::pokemon_batch))
+                .route("/pokemon/batch", web::post().to(route::pokemon_batch_post))
+                .route("/pokemon/by-id/{id}", web::get().to(route::pokemon_by_id))
                 .route("/pokemon/{name}", web::get().to(route::pokemon))
                 .route(
                     "/pokemon/translated/{name}",
                     web::get().to(route::pokemon_translated),
                 )
+                .route(
+                    "/pokemon/{name}/evolutions",
+                    web::get().to(route::pokemon_evolutions),
+                )
+                .route(
+                    "/pokemon/{name}/translation-rule",
+                    web::get().to(route::pokemon_translation_rule),
+                )
                 .app_data(pokemon_info.clone())
+                .app_data(pokemon_evolutions.clone())
+                .app_data(species_catalog.clone())
                 .app_data(pokemon_translator.clone())
+                .app_data(cache.clone())
+                .app_data(response_caching.clone())
+                .app_data(translation_quota.clone())
+                .app_data(circuits.clone())
+                .app_data(metrics.clone())
+                .app_data(web::PathConfig::default().error_handler(route::invalid_request))
+                .app_data(web::QueryConfig::default().error_handler(route::invalid_request))
+                .app_data(web::JsonConfig::default().error_handler(route::invalid_request))
+                .wrap_fn({
+                    let metrics = metrics.clone();
+                    move |request, service| {
+                        let metrics = metrics.clone();
+                        let observation = metrics.start_request();
+                        let method = request.method().to_string();
+                        // the unmatched requests share a single series
+                        let route = request
+                            .match_pattern()
+                            .unwrap_or_else(|| "unmatched".to_string());
+                        service.call(request).map(move |response| {
+                            let status = match &response {
+                                Ok(response) => response.status(),
+                                Err(error) => error.as_response_error().status_code(),
+                            };
+                            metrics.finish_request(observation, &method, &route, status.as_u16());
+                            response
+                        })
+                    }
+                })
                 .wrap(TracingLogger::default())
         })
         .listen(tcp_listener)
@@ -61,3 +197,98 @@ impl PokedexApp {
         Ok(PokedexApp { server, port })
     }
 }
+
+fn translation_rules(settings: &TranslationSettings) -> TranslationRules {
+    TranslationRules::new(
+        settings
+            .rules
+            .iter()
+            .map(|rule| {
+                TranslationRule::new(
+                    rule.name.clone(),
+                    RuleConditions::new(
+                        rule.habitat.clone(),
+                        rule.legendary,
+                        rule.mythical,
+                        rule.pokemon_type.clone(),
+                        rule.generation.clone(),
+                    ),
+                    TranslationStyle::new(&rule.style),
+                )
+            })
+            .collect(),
+        TranslationStyle::new(&settings.default_style),
+    )
+}
+
+fn retry_policy(settings: &RetrySettings) -> RetryPolicy {
+    RetryPolicy::new(
+        settings.max_attempts,
+        Duration::from_millis(settings.base_delay_ms),
+        Duration::from_millis(settings.max_delay_ms),
+        settings.jitter,
+        settings.retry_statuses.clone(),
+        RetryBudget::new(settings.budget_ratio, settings.budget_burst),
+    )
+}
+
+fn circuit(name: &str, settings: &CircuitBreakerSettings) -> Circuit {
+    Circuit::new(
+        name,
+        settings.failure_threshold,
+        settings.success_threshold,
+        Duration::from_secs(settings.open_seconds),
+    )
+}
+
+fn translator_modes(settings: &TranslationSettings) -> HashMap<TranslationStyle, TranslatorMode> {
+    settings
+        .modes
+        .iter()
+        .map(|(style, mode)| {
+            let mode = match mode {
+                TranslationMode::Funtranslation => TranslatorMode::Remote,
+                TranslationMode::Local => TranslatorMode::Local,
+                TranslationMode::Fallback => TranslatorMode::RemoteWithLocalFallback,
+            };
+            (TranslationStyle::new(style), mode)
+        })
+        .collect()
+}
+
+/// The cache of the pokemon retrieved from PokeAPI, disabled by a zero ttl.
+fn pokemon_cache(settings: &PokemonCacheSettings, cache: &CacheBackend) -> CacheBackend {
+    match settings.ttl_seconds {
+        0 => CacheBackend::Disabled,
+        _ => cache.clone(),
+    }
+}
+
+async fn build_cache(settings: &Settings) -> anyhow::Result<CacheBackend> {
+    // Redis refuses to store an entry with a zero expiry
+    if matches!(settings.cache.kind, CacheKind::Redis | CacheKind::Tiered)
+        && settings.redis_cache.ttl_seconds == 0
+    {
+        anyhow::bail!("The redis cache `ttl_seconds` must be greater than zero");
+    }
+    let memory_cache = || {
+        MemoryCache::new(
+            settings.memory_cache.capacity,
+            settings.memory_cache.ttl_seconds,
+        )
+    };
+    let redis_cache = || {
+        RedisCache::new(
+            settings.redis_cache.url.as_str(),
+            settings.redis_cache.ttl_seconds,
+        )
+    };
+    Ok(match settings.cache.kind {
+        CacheKind::None => CacheBackend::Disabled,
+        CacheKind::Memory => CacheBackend::Memory(memory_cache()),
+        CacheKind::Redis => CacheBackend::Redis(redis_cache().await?),
+        CacheKind::Tiered => {
+            CacheBackend::Tiered(TieredCache::new(memory_cache(), redis_cache().await?))
+        }
+    })
+}
@@ -1,16 +1,57 @@
+use anyhow::Context;
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::trace::{self, TracerProvider};
+use opentelemetry::sdk::Resource;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::{global, KeyValue};
+use reqwest::Url;
 use tracing::subscriber::set_global_default;
 use tracing_bunyan_formatter::{BunyanFormattingLayer, JsonStorageLayer};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::{EnvFilter, Registry};
 
-pub fn setup_tracing(name: String, env_filter: String) {
+/// Log the spans as Bunyan json on stdout and export them to `otlp_endpoint`, if any.
+///
+/// The W3C trace context of the incoming requests is propagated to the upstream services
+/// even when the traces are not exported.
+pub fn setup_tracing(
+    name: String,
+    env_filter: String,
+    otlp_endpoint: Option<&Url>,
+) -> anyhow::Result<()> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    let tracer =
+        match otlp_endpoint {
+            Some(endpoint) => opentelemetry_otlp::new_pipeline()
+                .with_endpoint(endpoint.as_str())
+                .with_trace_config(trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", name.clone()),
+                ])))
+                .with_tonic()
+                .install_batch(opentelemetry::runtime::Tokio)
+                .context("Failed to install the OTLP exporter")?,
+            None => {
+                let provider = TracerProvider::builder().build();
+                let tracer = provider.get_tracer("pokedex", None);
+                // the replaced provider is the default one, which records nothing
+                let _ = global::set_tracer_provider(provider);
+                tracer
+            }
+        };
+
     let env_filter =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(env_filter));
     let formatting_layer = BunyanFormattingLayer::new(name, std::io::stdout);
     let subscriber = Registry::default()
         .with(env_filter)
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
         .with(JsonStorageLayer)
         .with(formatting_layer);
 
-    set_global_default(subscriber).expect("Failed to set subscriber");
+    set_global_default(subscriber).context("Failed to set subscriber")
+}
+
+/// Export the spans not exported yet.
+pub fn shutdown_tracing() {
+    global::shutdown_tracer_provider();
 }
@@ -1,6 +1,6 @@
-pub use configuration::settings::load_configuration;
+pub use configuration::settings::{load_configuration, Settings, TranslationMode};
 pub use configuration::startup::PokedexApp;
-pub use configuration::telemetry::setup_tracing;
+pub use configuration::telemetry::{setup_tracing, shutdown_tracing};
 
 mod configuration;
 mod pokemon_bounded_context;
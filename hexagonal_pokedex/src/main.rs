@@ -1,20 +1,27 @@
 use anyhow::Context;
 
-use hexagonal_pokedex::{load_configuration, setup_tracing, PokedexApp};
+use hexagonal_pokedex::{load_configuration, setup_tracing, shutdown_tracing, PokedexApp};
 
 #[actix_web::main]
 async fn main() -> anyhow::Result<()> {
-    setup_tracing("pokedex".into(), "info".into());
-
     let config = load_configuration().context("Failed to load local configuration")?;
+
+    setup_tracing(
+        "pokedex".into(),
+        "info".into(),
+        config.telemetry.otlp_endpoint.as_ref(),
+    )?;
     tracing::info!("spawning server on url: {}", config.binding_address());
 
     let app = PokedexApp::new(config)
         .await
         .context("Failed to instantiate PokeapiApp")?;
 
-    app.server
+    let served = app
+        .server
         .context("Failed to start server")?
         .await
-        .map_err(Into::into)
+        .map_err(Into::into);
+    shutdown_tracing();
+    served
 }
@@ -0,0 +1,262 @@
+use std::time::Instant;
+
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+
+const STANDARD_METHODS: [&str; 9] = [
+    "CONNECT", "DELETE", "GET", "HEAD", "OPTIONS", "PATCH", "POST", "PUT", "TRACE",
+];
+
+/// The Prometheus metrics of the service, shared by the routes and the adapters.
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+    requests: IntCounterVec,
+    request_duration: HistogramVec,
+    requests_in_flight: IntGauge,
+    upstream_request_duration: HistogramVec,
+    upstream_errors: IntCounterVec,
+    translation_cache: IntCounterVec,
+    funtranslation_quota_remaining: IntGauge,
+}
+
+/// The metrics of the requests to a single upstream service.
+#[derive(Clone)]
+pub struct UpstreamMetrics {
+    upstream: String,
+    request_duration: HistogramVec,
+    errors: IntCounterVec,
+}
+
+/// The metrics of the cache lookups and updates of the translations.
+#[derive(Clone)]
+pub struct CacheMetrics {
+    outcomes: IntCounterVec,
+}
+
+/// The requests left in the FunTranslations quota.
+#[derive(Clone)]
+pub struct QuotaMetrics {
+    remaining: IntGauge,
+}
+
+/// A request being served, counted in flight until it is finished.
+pub struct RequestObservation {
+    requests_in_flight: IntGauge,
+    started_at: Instant,
+}
+
+impl Metrics {
+    pub fn new() -> Result<Self, prometheus::Error> {
+        let registry = Registry::new_custom(Some("pokedex".to_string()), None)?;
+
+        let requests = IntCounterVec::new(
+            Opts::new(
+                "http_requests_total",
+                "Requests served, by route and status",
+            ),
+            &["method", "route", "status"],
+        )?;
+        let request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Latency of the requests served, by route and status",
+            ),
+            &["method", "route", "status"],
+        )?;
+        let requests_in_flight = IntGauge::new("http_requests_in_flight", "Requests being served")?;
+        let upstream_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "upstream_request_duration_seconds",
+                "Latency of every attempt of the requests to the upstream services",
+            ),
+            &["upstream"],
+        )?;
+        let upstream_errors = IntCounterVec::new(
+            Opts::new(
+                "upstream_errors_total",
+                "Failed attempts of the requests to the upstream services, by error",
+            ),
+            &["upstream", "error"],
+        )?;
+        let translation_cache = IntCounterVec::new(
+            Opts::new(
+                "translation_cache_total",
+                "Lookups and updates of the translations cache, by outcome",
+            ),
+            &["outcome"],
+        )?;
+        let funtranslation_quota_remaining = IntGauge::new(
+            "funtranslation_quota_remaining",
+            "Requests left in the FunTranslations quota",
+        )?;
+
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(request_duration.clone()))?;
+        registry.register(Box::new(requests_in_flight.clone()))?;
+        registry.register(Box::new(upstream_request_duration.clone()))?;
+        registry.register(Box::new(upstream_errors.clone()))?;
+        registry.register(Box::new(translation_cache.clone()))?;
+        registry.register(Box::new(funtranslation_quota_remaining.clone()))?;
+
+        Ok(Self {
+            registry,
+            requests,
+            request_duration,
+            requests_in_flight,
+            upstream_request_duration,
+            upstream_errors,
+            translation_cache,
+            funtranslation_quota_remaining,
+        })
+    }
+
+    pub fn upstream(&self, upstream: &str) -> UpstreamMetrics {
+        UpstreamMetrics {
+            upstream: upstream.to_string(),
+            request_duration: self.upstream_request_duration.clone(),
+            errors: self.upstream_errors.clone(),
+        }
+    }
+
+    pub fn translation_cache(&self) -> CacheMetrics {
+        CacheMetrics {
+            outcomes: self.translation_cache.clone(),
+        }
+    }
+
+    pub fn funtranslation_quota(&self) -> QuotaMetrics {
+        QuotaMetrics {
+            remaining: self.funtranslation_quota_remaining.clone(),
+        }
+    }
+
+    pub fn start_request(&self) -> RequestObservation {
+        self.requests_in_flight.inc();
+        RequestObservation {
+            requests_in_flight: self.requests_in_flight.clone(),
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Record a served request: `route` is the pattern of the matched route,
+    /// so that the pokemon names do not make a distinct series each, and the
+    /// non-standard methods chosen by the clients are all recorded as `other`.
+    pub fn finish_request(
+        &self,
+        observation: RequestObservation,
+        method: &str,
+        route: &str,
+        status: u16,
+    ) {
+        let method = if STANDARD_METHODS.contains(&method) {
+            method
+        } else {
+            "other"
+        };
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.requests.with_label_values(&labels).inc();
+        self.request_duration
+            .with_label_values(&labels)
+            .observe(observation.started_at.elapsed().as_secs_f64());
+    }
+
+    /// All the metrics in the Prometheus text format.
+    pub fn render(&self) -> Result<String, prometheus::Error> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        String::from_utf8(buffer).map_err(|e| prometheus::Error::Msg(e.to_string()))
+    }
+}
+
+impl UpstreamMetrics {
+    pub fn observe(&self, started_at: Instant) {
+        self.request_duration
+            .with_label_values(&[&self.upstream])
+            .observe(started_at.elapsed().as_secs_f64());
+    }
+    pub fn error(&self, error: &str) {
+        self.errors
+            .with_label_values(&[&self.upstream, error])
+            .inc();
+    }
+}
+
+impl CacheMetrics {
+    pub fn hit(&self) {
+        self.outcomes.with_label_values(&["hit"]).inc();
+    }
+    pub fn miss(&self) {
+        self.outcomes.with_label_values(&["miss"]).inc();
+    }
+    pub fn error(&self) {
+        self.outcomes.with_label_values(&["error"]).inc();
+    }
+}
+
+impl QuotaMetrics {
+    pub fn set_remaining(&self, remaining: u32) {
+        self.remaining.set(remaining.into());
+    }
+}
+
+impl Drop for RequestObservation {
+    fn drop(&mut self) {
+        self.requests_in_flight.dec();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metrics_render_the_requests_by_route_and_status() {
+        let metrics = Metrics::new().unwrap();
+        let observation = metrics.start_request();
+        assert!(metrics
+            .render()
+            .unwrap()
+            .contains("pokedex_http_requests_in_flight 1"));
+
+        metrics.finish_request(observation, "GET", "/pokemon/{name}", 200);
+        metrics.upstream("poke_api").error("timeout");
+        metrics.translation_cache().hit();
+
+        let rendered = metrics.render().unwrap();
+        assert!(rendered.contains("pokedex_http_requests_in_flight 0"));
+        assert!(rendered.contains(
+            "pokedex_http_requests_total{method=\"GET\",route=\"/pokemon/{name}\",status=\"200\"} 1"
+        ));
+        assert!(rendered
+            .contains("pokedex_upstream_errors_total{error=\"timeout\",upstream=\"poke_api\"} 1"));
+        assert!(rendered.contains("pokedex_translation_cache_total{outcome=\"hit\"} 1"));
+    }
+
+    #[test]
+    fn metrics_record_the_non_standard_methods_as_other() {
+        let metrics = Metrics::new().unwrap();
+
+        for method in ["BREW", "PROPFIND", "BREW"] {
+            metrics.finish_request(metrics.start_request(), method, "unmatched", 404);
+        }
+
+        let rendered = metrics.render().unwrap();
+        assert!(rendered.contains(
+            "pokedex_http_requests_total{method=\"other\",route=\"unmatched\",status=\"404\"} 3"
+        ));
+        assert!(!rendered.contains("BREW"));
+    }
+
+    #[test]
+    fn metrics_render_the_remaining_quota() {
+        let metrics = Metrics::new().unwrap();
+
+        metrics.funtranslation_quota().set_remaining(3);
+
+        let rendered = metrics.render().unwrap();
+        assert!(rendered.contains("pokedex_funtranslation_quota_remaining 3"));
+    }
+}
@@ -1,7 +1,26 @@
+pub use cache_backend::{CacheBackend, CacheStatus};
+pub use caching_pokemon_retrieval::CachingPokemonRetrieval;
+pub use circuit_breaker::{Circuit, CircuitBreaker, CircuitStatus};
 pub use funtranslation_api::client::FuntranslationApi;
+pub use funtranslation_api::quota::{QuotaStatus, TranslationQuota};
+pub use http::{RetryBudget, RetryPolicy};
+pub use local_translator::LocalTranslator;
+pub use memory_cache::MemoryCache;
+pub use metered_cache::MeteredCache;
 pub use poke_api::client::PokeApi;
 pub use redis_cache::RedisCache;
+pub use tiered_cache::{ExpiringCache, TieredCache};
+pub use translator_router::{TranslatorMode, TranslatorRouter};
 
+mod cache_backend;
+mod caching_pokemon_retrieval;
+mod circuit_breaker;
 mod funtranslation_api;
+mod http;
+mod local_translator;
+mod memory_cache;
+mod metered_cache;
 mod poke_api;
 mod redis_cache;
+mod tiered_cache;
+mod translator_router;
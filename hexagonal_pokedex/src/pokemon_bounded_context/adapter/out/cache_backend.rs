@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use crate::pokemon_bounded_context::adapter::out::{MemoryCache, RedisCache, TieredCache};
+use crate::pokemon_bounded_context::domain::{CacheKey, PokemonError};
+use crate::pokemon_bounded_context::port::out::{CacheRetrieval, CacheUpdater};
+
+/// The cache selected by the operator in the configuration.
+#[derive(Clone)]
+pub enum CacheBackend {
+    Disabled,
+    Memory(MemoryCache),
+    Redis(RedisCache),
+    Tiered(TieredCache<MemoryCache, RedisCache>),
+}
+
+/// Availability of the cache, as reported by the readiness endpoint.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheStatus {
+    Disabled,
+    Available,
+    /// Only the in-memory level of the tiered cache is available.
+    Degraded,
+    Unavailable,
+}
+
+impl CacheBackend {
+    pub fn status(&self) -> CacheStatus {
+        match self {
+            CacheBackend::Disabled => CacheStatus::Disabled,
+            CacheBackend::Memory(_) => CacheStatus::Available,
+            CacheBackend::Redis(cache) if cache.is_connected() => CacheStatus::Available,
+            CacheBackend::Redis(_) => CacheStatus::Unavailable,
+            CacheBackend::Tiered(cache) if cache.l2().is_connected() => CacheStatus::Available,
+            CacheBackend::Tiered(_) => CacheStatus::Degraded,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheRetrieval for CacheBackend {
+    #[tracing::instrument(
+        name = "Getting from the cache",
+        skip(self),
+        fields(key = %key, outcome)
+    )]
+    async fn get(&self, key: &CacheKey) -> Result<Option<String>, PokemonError> {
+        let value = match self {
+            CacheBackend::Disabled => Ok(None),
+            CacheBackend::Memory(cache) => cache.get(key).await,
+            CacheBackend::Redis(cache) => cache.get(key).await,
+            CacheBackend::Tiered(cache) => cache.get(key).await,
+        };
+        let outcome = match &value {
+            Ok(Some(_)) => "hit",
+            Ok(None) => "miss",
+            Err(_) => "error",
+        };
+        tracing::Span::current().record("outcome", &outcome);
+        value
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheUpdater for CacheBackend {
+    #[tracing::instrument(
+        name = "Storing in the cache",
+        skip(self, pokemon_description),
+        fields(key = %key)
+    )]
+    async fn update(
+        &self,
+        key: &CacheKey,
+        pokemon_description: String,
+    ) -> Result<(), PokemonError> {
+        match self {
+            CacheBackend::Disabled => Ok(()),
+            CacheBackend::Memory(cache) => cache.update(key, pokemon_description).await,
+            CacheBackend::Redis(cache) => cache.update(key, pokemon_description).await,
+            CacheBackend::Tiered(cache) => cache.update(key, pokemon_description).await,
+        }
+    }
+    #[tracing::instrument(name = "Storing in the cache", skip(self, value), fields(key = %key))]
+    async fn update_with_ttl(
+        &self,
+        key: &CacheKey,
+        value: String,
+        ttl: Duration,
+    ) -> Result<(), PokemonError> {
+        match self {
+            CacheBackend::Disabled => Ok(()),
+            CacheBackend::Memory(cache) => cache.update_with_ttl(key, value, ttl).await,
+            CacheBackend::Redis(cache) => cache.update_with_ttl(key, value, ttl).await,
+            CacheBackend::Tiered(cache) => cache.update_with_ttl(key, value, ttl).await,
+        }
+    }
+}
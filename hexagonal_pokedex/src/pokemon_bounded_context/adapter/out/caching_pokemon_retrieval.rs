@@ -0,0 +1,270 @@
+use std::time::Duration;
+
+use futures::future::join_all;
+
+use crate::pokemon_bounded_context::domain::{
+    CacheKey, Language, Pokemon, PokemonError, PokemonLookup,
+};
+use crate::pokemon_bounded_context::port::out::{CacheRetrieval, CacheUpdater, PokemonRetrieval};
+
+/// Decorator caching the pokemon retrieved by the `inner` port for `ttl`,
+/// and the unknown ones for `not_found_ttl`.
+///
+/// Cache failures are only logged, falling back to the `inner` port.
+pub struct CachingPokemonRetrieval<P, C> {
+    inner: P,
+    cache: C,
+    ttl: Duration,
+    not_found_ttl: Duration,
+}
+
+impl<P, C> CachingPokemonRetrieval<P, C>
+where
+    C: CacheRetrieval + CacheUpdater + Send + Sync,
+{
+    pub fn new(inner: P, cache: C, ttl: Duration, not_found_ttl: Duration) -> Self {
+        Self {
+            inner,
+            cache,
+            ttl,
+            not_found_ttl,
+        }
+    }
+
+    /// The cached pokemon, `Some(None)` when it is cached as not found.
+    async fn cached(&self, key: &CacheKey) -> Option<Option<Pokemon>> {
+        match self.cache.get(key).await {
+            Ok(Some(value)) => match serde_json::from_str(&value) {
+                Ok(pokemon) => Some(pokemon),
+                Err(e) => {
+                    tracing::warn!("Failed to parse the cached pokemon {}.\n{:?}", key, e);
+                    None
+                }
+            },
+            Ok(None) => None,
+            Err(e) => {
+                tracing::warn!("Failed to retrieve the cached pokemon.\n{:?}", e);
+                None
+            }
+        }
+    }
+
+    async fn store(&self, key: &CacheKey, pokemon: Option<&Pokemon>) {
+        let ttl = match pokemon {
+            Some(_) => self.ttl,
+            None => self.not_found_ttl,
+        };
+        let value = match serde_json::to_string(&pokemon) {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!("Failed to serialize the pokemon {}.\n{:?}", key, e);
+                return;
+            }
+        };
+        if let Err(e) = self.cache.update_with_ttl(key, value, ttl).await {
+            tracing::warn!("Failed to update the pokemon cache.\n{:?}", e);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, C> PokemonRetrieval for CachingPokemonRetrieval<P, C>
+where
+    P: PokemonRetrieval + Send + Sync,
+    C: CacheRetrieval + CacheUpdater + Send + Sync,
+{
+    #[tracing::instrument(
+        name = "Retrieving pokemon through the cache",
+        skip(self, lookup, languages),
+        fields(pokemon_name = %lookup, cache)
+    )]
+    async fn get(
+        &self,
+        lookup: PokemonLookup,
+        languages: Vec<Language>,
+    ) -> Result<Pokemon, PokemonError> {
+        let key = CacheKey::pokemon(lookup.clone(), languages.clone());
+        let cached = self.cached(&key).await;
+        tracing::Span::current().record("cache", &if cached.is_some() { "hit" } else { "miss" });
+        if let Some(pokemon) = cached {
+            return pokemon.ok_or(PokemonError::NotFound);
+        }
+        let pokemon = self.inner.get(lookup, languages).await;
+        match &pokemon {
+            Ok(pokemon) => self.store(&key, Some(pokemon)).await,
+            Err(PokemonError::NotFound) => self.store(&key, None).await,
+            Err(_) => {}
+        }
+        pokemon
+    }
+    /// Only the pokemon missing from the cache are retrieved from the `inner` port.
+    #[tracing::instrument(
+        name = "Retrieving many pokemon through the cache",
+        skip(self, pokemon_names, languages),
+        fields(pokemon_count = pokemon_names.len(), cache_misses)
+    )]
+    async fn get_many(
+        &self,
+        pokemon_names: Vec<String>,
+        languages: Vec<Language>,
+    ) -> Result<Vec<Pokemon>, PokemonError> {
+        let keys: Vec<CacheKey> = pokemon_names
+            .iter()
+            .map(|pokemon_name| {
+                CacheKey::pokemon(
+                    PokemonLookup::ByName(pokemon_name.clone()),
+                    languages.clone(),
+                )
+            })
+            .collect();
+        let cached = join_all(keys.iter().map(|key| self.cached(key))).await;
+
+        let mut found = Vec::with_capacity(pokemon_names.len());
+        let mut missing = Vec::new();
+        for ((pokemon_name, key), pokemon) in pokemon_names.into_iter().zip(keys).zip(cached) {
+            match pokemon {
+                Some(Some(pokemon)) => found.push(pokemon),
+                Some(None) => {}
+                None => missing.push((pokemon_name, key)),
+            }
+        }
+        tracing::Span::current().record("cache_misses", &missing.len());
+        if missing.is_empty() {
+            return Ok(found);
+        }
+
+        let retrieved = self
+            .inner
+            .get_many(
+                missing.iter().map(|(name, _)| name.clone()).collect(),
+                languages,
+            )
+            .await?;
+        for (pokemon_name, key) in &missing {
+            let pokemon = retrieved
+                .iter()
+                .find(|pokemon| pokemon.name() == pokemon_name);
+            self.store(key, pokemon).await;
+        }
+        found.extend(retrieved);
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use serde_json::json;
+
+    use crate::pokemon_bounded_context::adapter::out::MemoryCache;
+    use crate::pokemon_bounded_context::port::out::MockPokemonRetrieval;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn caching_pokemon_retrieval_serves_the_cached_pokemon() {
+        let mut port = MockPokemonRetrieval::new();
+        port.expect_get()
+            .with(eq(mewtwo()), eq(english()))
+            .times(1)
+            .returning(|_, _| Ok(pokemon("mewtwo")));
+
+        let retrieval = caching_pokemon_retrieval(port);
+        for _ in 0..2 {
+            let pokemon = retrieval.get(mewtwo(), english()).await.unwrap();
+            assert_eq!(json!(self::pokemon("mewtwo")), json!(pokemon));
+        }
+    }
+
+    #[tokio::test]
+    async fn caching_pokemon_retrieval_caches_unknown_pokemon() {
+        let mut port = MockPokemonRetrieval::new();
+        port.expect_get()
+            .times(1)
+            .returning(|_, _| Err(PokemonError::NotFound));
+
+        let retrieval = caching_pokemon_retrieval(port);
+        for _ in 0..2 {
+            assert!(matches!(
+                retrieval
+                    .get(PokemonLookup::ByName("missingno".to_string()), english())
+                    .await,
+                Err(PokemonError::NotFound)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn caching_pokemon_retrieval_does_not_cache_upstream_failures() {
+        let mut port = MockPokemonRetrieval::new();
+        port.expect_get().times(2).returning(|_, _| {
+            Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                "any_error"
+            )))
+        });
+
+        let retrieval = caching_pokemon_retrieval(port);
+        for _ in 0..2 {
+            assert!(retrieval.get(mewtwo(), english()).await.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn caching_pokemon_retrieval_retrieves_only_the_missing_pokemon() {
+        let mut port = MockPokemonRetrieval::new();
+        port.expect_get()
+            .times(1)
+            .returning(|_, _| Ok(pokemon("mewtwo")));
+        port.expect_get_many()
+            .with(
+                eq(vec!["pikachu".to_string(), "missingno".to_string()]),
+                eq(english()),
+            )
+            .times(1)
+            .returning(|_, _| Ok(vec![pokemon("pikachu")]));
+
+        let retrieval = caching_pokemon_retrieval(port);
+        retrieval.get(mewtwo(), english()).await.unwrap();
+
+        let names = vec![
+            "mewtwo".to_string(),
+            "pikachu".to_string(),
+            "missingno".to_string(),
+        ];
+        for _ in 0..2 {
+            let found = retrieval.get_many(names.clone(), english()).await.unwrap();
+            let mut found: Vec<&str> = found.iter().map(Pokemon::name).collect();
+            found.sort_unstable();
+            assert_eq!(vec!["mewtwo", "pikachu"], found);
+        }
+    }
+
+    fn caching_pokemon_retrieval(
+        port: MockPokemonRetrieval,
+    ) -> CachingPokemonRetrieval<MockPokemonRetrieval, MemoryCache> {
+        CachingPokemonRetrieval::new(
+            port,
+            MemoryCache::new(10, 60),
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+        )
+    }
+
+    fn pokemon(name: &str) -> Pokemon {
+        Pokemon::new(
+            Some("any_description".to_string()),
+            Some("cave".to_string()),
+            true,
+            Some(Language::new("en")),
+            name.to_string(),
+        )
+    }
+
+    fn mewtwo() -> PokemonLookup {
+        PokemonLookup::ByName("mewtwo".to_string())
+    }
+
+    fn english() -> Vec<Language> {
+        vec![Language::new("en")]
+    }
+}
@@ -0,0 +1,403 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::pokemon_bounded_context::domain::{
+    EvolutionChain, Language, PageRequest, Pokemon, PokemonError, PokemonLookup, SpeciesFilter,
+    SpeciesSummary, Translated, TranslationSource, TranslationStyle,
+};
+use crate::pokemon_bounded_context::port::out::{
+    EvolutionRetrieval, PokemonRetrieval, SpeciesListing, SpeciesRetrieval, Translator,
+};
+
+/// State of the calls to an upstream service, shared by the breakers wrapping its clients.
+///
+/// It opens after `failure_threshold` consecutive failures, failing fast for `open_duration`.
+/// Then it lets a single trial call through at a time, closing after `success_threshold`
+/// successful ones and opening again at the first failure.
+#[derive(Clone)]
+pub struct Circuit {
+    name: String,
+    failure_threshold: u32,
+    success_threshold: u32,
+    open_duration: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Closed { failures: u32 },
+    Open { since: Instant },
+    HalfOpen { successes: u32, trial: bool },
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitStatus {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+/// Decorator failing fast, without calling the `inner` port, while its circuit is open.
+#[derive(Clone)]
+pub struct CircuitBreaker<P> {
+    inner: P,
+    circuit: Circuit,
+}
+
+/// A call let through the circuit: dropping it without its outcome only frees the trial.
+struct Permit<'a> {
+    circuit: &'a Circuit,
+    trial: bool,
+    recorded: bool,
+}
+
+impl Circuit {
+    pub fn new(
+        name: impl Into<String>,
+        failure_threshold: u32,
+        success_threshold: u32,
+        open_duration: Duration,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            failure_threshold: failure_threshold.max(1),
+            success_threshold: success_threshold.max(1),
+            open_duration,
+            state: Arc::new(Mutex::new(State::Closed { failures: 0 })),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn status(&self) -> CircuitStatus {
+        match *self.state() {
+            State::Closed { .. } => CircuitStatus::Closed,
+            State::Open { .. } => CircuitStatus::Open,
+            State::HalfOpen { .. } => CircuitStatus::HalfOpen,
+        }
+    }
+
+    fn acquire(&self) -> Result<Permit<'_>, PokemonError> {
+        self.acquire_at(Instant::now())
+    }
+
+    fn acquire_at(&self, now: Instant) -> Result<Permit<'_>, PokemonError> {
+        let mut state = self.state();
+        let trial = match *state {
+            State::Closed { .. } => false,
+            State::Open { since } if now.saturating_duration_since(since) >= self.open_duration => {
+                tracing::info!(circuit = %self.name, "Circuit half-open, trying a call");
+                *state = State::HalfOpen {
+                    successes: 0,
+                    trial: true,
+                };
+                true
+            }
+            State::HalfOpen {
+                successes,
+                trial: false,
+            } => {
+                *state = State::HalfOpen {
+                    successes,
+                    trial: true,
+                };
+                true
+            }
+            State::Open { .. } | State::HalfOpen { trial: true, .. } => {
+                return Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                    "The circuit of `{}` is open",
+                    self.name
+                )));
+            }
+        };
+        Ok(Permit {
+            circuit: self,
+            trial,
+            recorded: false,
+        })
+    }
+
+    /// Record the outcome of a call: `None` when it tells nothing about the upstream health.
+    fn record_at(&self, trial: bool, success: Option<bool>, now: Instant) {
+        let mut state = self.state();
+        *state = match (*state, success) {
+            (State::Closed { .. }, Some(true)) => State::Closed { failures: 0 },
+            (State::Closed { failures }, Some(false)) if failures + 1 >= self.failure_threshold => {
+                tracing::warn!(circuit = %self.name, "Circuit open after {} failures", failures + 1);
+                State::Open { since: now }
+            }
+            (State::Closed { failures }, Some(false)) => State::Closed {
+                failures: failures + 1,
+            },
+            (State::HalfOpen { successes, .. }, Some(true)) if trial => {
+                if successes + 1 >= self.success_threshold {
+                    tracing::info!(circuit = %self.name, "Circuit closed");
+                    State::Closed { failures: 0 }
+                } else {
+                    State::HalfOpen {
+                        successes: successes + 1,
+                        trial: false,
+                    }
+                }
+            }
+            (State::HalfOpen { .. }, Some(false)) if trial => {
+                tracing::warn!(circuit = %self.name, "Circuit open again after a failed trial");
+                State::Open { since: now }
+            }
+            (State::HalfOpen { successes, .. }, None) if trial => State::HalfOpen {
+                successes,
+                trial: false,
+            },
+            // late outcomes of the calls let through before the circuit changed
+            (state, _) => state,
+        };
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Permit<'_> {
+    /// Only the upstream timeouts and failures count against the circuit.
+    fn record<T>(self, result: &Result<T, PokemonError>) {
+        let success = match result {
+            Ok(_) => Some(true),
+            Err(PokemonError::UpstreamTimeout(_)) | Err(PokemonError::UpstreamUnavailable(_)) => {
+                Some(false)
+            }
+            Err(_) => None,
+        };
+        self.record_at(success, Instant::now());
+    }
+    fn record_at(mut self, success: Option<bool>, now: Instant) {
+        self.circuit.record_at(self.trial, success, now);
+        self.recorded = true;
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if !self.recorded {
+            self.circuit.record_at(self.trial, None, Instant::now());
+        }
+    }
+}
+
+impl<P> CircuitBreaker<P> {
+    pub fn new(inner: P, circuit: Circuit) -> Self {
+        Self { inner, circuit }
+    }
+
+    async fn call<T>(
+        &self,
+        call: impl Future<Output = Result<T, PokemonError>>,
+    ) -> Result<T, PokemonError> {
+        let permit = self.circuit.acquire()?;
+        let result = call.await;
+        permit.record(&result);
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl<P> PokemonRetrieval for CircuitBreaker<P>
+where
+    P: PokemonRetrieval + Send + Sync,
+{
+    async fn get(
+        &self,
+        lookup: PokemonLookup,
+        languages: Vec<Language>,
+    ) -> Result<Pokemon, PokemonError> {
+        self.call(self.inner.get(lookup, languages)).await
+    }
+    async fn get_many(
+        &self,
+        pokemon_names: Vec<String>,
+        languages: Vec<Language>,
+    ) -> Result<Vec<Pokemon>, PokemonError> {
+        self.call(self.inner.get_many(pokemon_names, languages))
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<P> EvolutionRetrieval for CircuitBreaker<P>
+where
+    P: EvolutionRetrieval + Send + Sync,
+{
+    async fn get_evolutions(&self, pokemon_name: String) -> Result<EvolutionChain, PokemonError> {
+        self.call(self.inner.get_evolutions(pokemon_name)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<P> SpeciesRetrieval for CircuitBreaker<P>
+where
+    P: SpeciesRetrieval + Send + Sync,
+{
+    async fn get_names(&self) -> Result<Vec<String>, PokemonError> {
+        self.call(self.inner.get_names()).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<P> SpeciesListing for CircuitBreaker<P>
+where
+    P: SpeciesListing + Send + Sync,
+{
+    async fn list(
+        &self,
+        filter: SpeciesFilter,
+        page: PageRequest,
+    ) -> Result<(Vec<SpeciesSummary>, u64), PokemonError> {
+        self.call(self.inner.list(filter, page)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<P> Translator for CircuitBreaker<P>
+where
+    P: Translator + Send + Sync,
+{
+    fn supports(&self, style: &TranslationStyle) -> bool {
+        self.inner.supports(style)
+    }
+    fn source(&self, style: &TranslationStyle) -> TranslationSource {
+        self.inner.source(style)
+    }
+    async fn translate(
+        &self,
+        style: &TranslationStyle,
+        text: &str,
+    ) -> Result<Translated, PokemonError> {
+        self.call(self.inner.translate(style, text)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pokemon_bounded_context::port::out::MockPokemonRetrieval;
+
+    use super::*;
+
+    #[test]
+    fn circuit_opens_after_consecutive_failures() {
+        let circuit = circuit();
+        let now = Instant::now();
+
+        fail(&circuit, now);
+        assert_eq!(CircuitStatus::Closed, circuit.status());
+        fail(&circuit, now);
+        assert_eq!(CircuitStatus::Open, circuit.status());
+        assert!(circuit.acquire_at(now).is_err());
+    }
+
+    #[test]
+    fn circuit_resets_failures_after_a_success() {
+        let circuit = circuit();
+        let now = Instant::now();
+
+        fail(&circuit, now);
+        succeed(&circuit, now);
+        fail(&circuit, now);
+        assert_eq!(CircuitStatus::Closed, circuit.status());
+    }
+
+    #[test]
+    fn circuit_lets_a_single_trial_through_once_half_open() {
+        let circuit = circuit();
+        let now = Instant::now();
+        fail(&circuit, now);
+        fail(&circuit, now);
+
+        let later = now + Duration::from_secs(31);
+        let trial = circuit.acquire_at(later).unwrap();
+        assert_eq!(CircuitStatus::HalfOpen, circuit.status());
+        assert!(circuit.acquire_at(later).is_err());
+        drop(trial);
+        assert!(circuit.acquire_at(later).is_ok());
+    }
+
+    #[test]
+    fn circuit_closes_after_successful_trials() {
+        let circuit = circuit();
+        let now = Instant::now();
+        fail(&circuit, now);
+        fail(&circuit, now);
+
+        let later = now + Duration::from_secs(31);
+        succeed(&circuit, later);
+        assert_eq!(CircuitStatus::HalfOpen, circuit.status());
+        succeed(&circuit, later);
+        assert_eq!(CircuitStatus::Closed, circuit.status());
+    }
+
+    #[test]
+    fn circuit_opens_again_after_a_failed_trial() {
+        let circuit = circuit();
+        let now = Instant::now();
+        fail(&circuit, now);
+        fail(&circuit, now);
+
+        let later = now + Duration::from_secs(31);
+        fail(&circuit, later);
+        assert_eq!(CircuitStatus::Open, circuit.status());
+        assert!(circuit.acquire_at(later + Duration::from_secs(1)).is_err());
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_fails_fast_while_open() {
+        let mut port = MockPokemonRetrieval::new();
+        port.expect_get().times(2).returning(|_, _| {
+            Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                "any_error"
+            )))
+        });
+
+        let breaker = CircuitBreaker::new(port, circuit());
+        for _ in 0..3 {
+            assert!(matches!(
+                breaker.get(PokemonLookup::ById(1), vec![]).await,
+                Err(PokemonError::UpstreamUnavailable(_))
+            ));
+        }
+        assert_eq!(CircuitStatus::Open, breaker.circuit.status());
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_ignores_errors_of_the_request() {
+        let mut port = MockPokemonRetrieval::new();
+        port.expect_get()
+            .times(3)
+            .returning(|_, _| Err(PokemonError::NotFound));
+
+        let breaker = CircuitBreaker::new(port, circuit());
+        for _ in 0..3 {
+            assert!(matches!(
+                breaker.get(PokemonLookup::ById(1), vec![]).await,
+                Err(PokemonError::NotFound)
+            ));
+        }
+        assert_eq!(CircuitStatus::Closed, breaker.circuit.status());
+    }
+
+    fn circuit() -> Circuit {
+        Circuit::new("any_upstream", 2, 2, Duration::from_secs(30))
+    }
+
+    fn fail(circuit: &Circuit, now: Instant) {
+        circuit.acquire_at(now).unwrap().record_at(Some(false), now);
+    }
+
+    fn succeed(circuit: &Circuit, now: Instant) {
+        circuit.acquire_at(now).unwrap().record_at(Some(true), now);
+    }
+}
@@ -1,62 +1,147 @@
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::time::Duration;
 
 use anyhow::Context;
-use reqwest::{Client, Response, Url};
+use reqwest::header::{HeaderName, RETRY_AFTER};
+use reqwest::{Client, Response, StatusCode, Url};
 
-use crate::pokemon_bounded_context::adapter::out::funtranslation_api::io::{Input, Output};
-use crate::pokemon_bounded_context::port::out::{ShakespeareTranslator, YodaTranslator};
+use crate::pokemon_bounded_context::adapter::out::funtranslation_api::io::{Input, Reply};
+use crate::pokemon_bounded_context::adapter::out::funtranslation_api::quota::TranslationQuota;
+use crate::pokemon_bounded_context::adapter::out::http::{request_error, RetryPolicy};
+use crate::pokemon_bounded_context::domain::{
+    PokemonError, Translated, TranslationSource, TranslationStyle,
+};
+use crate::pokemon_bounded_context::port::out::Translator;
 
 pub struct FuntranslationApi {
     client: Client,
     url: Url,
+    endpoints: HashMap<TranslationStyle, String>,
+    quota: TranslationQuota,
+    retry_policy: RetryPolicy,
 }
 
-#[async_trait::async_trait]
-impl YodaTranslator for FuntranslationApi {
-    async fn to_yoda(&self, text: &str) -> anyhow::Result<String> {
-        let response = self
-            .execute_post_request(self.url.join("yoda.json")?.as_str(), text)
-            .await?;
-        parse_response(response).await
-    }
-}
+const RATE_LIMIT_REMAINING: &str = "x-ratelimit-remaining";
 
 #[async_trait::async_trait]
-impl ShakespeareTranslator for FuntranslationApi {
-    async fn to_shakespeare(&self, text: &str) -> anyhow::Result<String> {
-        let response = self
-            .execute_post_request(self.url.join("shakespeare.json")?.as_str(), text)
-            .await?;
-        parse_response(response).await
+impl Translator for FuntranslationApi {
+    fn supports(&self, style: &TranslationStyle) -> bool {
+        self.endpoints.contains_key(style)
+    }
+    fn source(&self, _style: &TranslationStyle) -> TranslationSource {
+        TranslationSource::Remote
+    }
+    #[tracing::instrument(
+        name = "Translating with FunTranslations",
+        skip(self, text),
+        fields(style = %style)
+    )]
+    async fn translate(
+        &self,
+        style: &TranslationStyle,
+        text: &str,
+    ) -> Result<Translated, PokemonError> {
+        let endpoint = self
+            .endpoints
+            .get(style)
+            .ok_or_else(|| PokemonError::UnknownStyle(style.to_string()))?;
+        let response = self.execute_post_request(endpoint, text).await?;
+        let translation = self.parse_response(response).await;
+        tracing::debug!(
+            funtranslation_quota_remaining = self.quota.status().remaining(),
+            "FunTranslations quota"
+        );
+        translation.map(|translation| Translated::new(translation, TranslationSource::Remote))
     }
 }
 
 impl FuntranslationApi {
-    pub fn new(url: Url, timeout_second: u64) -> anyhow::Result<Self> {
+    /// Build the client for the translation `styles`,
+    /// each one mapped to its endpoint relative to `url`, e.g. `pirate` to `pirate.json`.
+    ///
+    /// A translation takes a single request from the `quota`, whatever its retries,
+    /// and none is sent while the `quota` is exhausted.
+    pub fn new(
+        url: Url,
+        timeout_second: u64,
+        styles: &HashMap<String, String>,
+        quota: TranslationQuota,
+        retry_policy: RetryPolicy,
+    ) -> anyhow::Result<Self> {
         Ok(Self {
             client: Client::builder()
                 .timeout(Duration::from_secs(timeout_second))
                 .build()
                 .context(format!("Error creating client with:\nurl: {}", url,))?,
             url,
+            endpoints: styles
+                .iter()
+                .map(|(style, endpoint)| (TranslationStyle::new(style), endpoint.clone()))
+                .collect(),
+            quota,
+            retry_policy,
         })
     }
-    async fn execute_post_request(&self, endpoint: &str, text: &str) -> anyhow::Result<Response> {
-        self.client
-            .post(endpoint)
-            .form(&Input { text })
-            .send()
+    async fn execute_post_request(
+        &self,
+        endpoint: &str,
+        text: &str,
+    ) -> Result<Response, PokemonError> {
+        let endpoint = self
+            .url
+            .join(endpoint)
+            .map_err(|e| PokemonError::UpstreamUnavailable(e.into()))?;
+        if !self.quota.try_acquire() {
+            return Err(PokemonError::RateLimited);
+        }
+        self.retry_policy
+            .execute(|| Ok(self.client.post(endpoint.clone()).form(&Input { text })))
             .await
-            .context("Failed to send request")
+    }
+    /// Read the translation, keeping the quota in line with the one reported by FunTranslations.
+    async fn parse_response(&self, response: Response) -> Result<String, PokemonError> {
+        if let Some(remaining) =
+            header_value(&response, HeaderName::from_static(RATE_LIMIT_REMAINING))
+        {
+            self.quota.sync_remaining(remaining);
+        }
+        let retry_after = header_value(&response, RETRY_AFTER).map(Duration::from_secs);
+        let status = response.status();
+        match response.json::<Reply>().await {
+            Ok(Reply::Failed(envelope)) if envelope.is_rate_limited() => {
+                self.rate_limited(retry_after)
+            }
+            _ if status == StatusCode::TOO_MANY_REQUESTS => self.rate_limited(retry_after),
+            Ok(Reply::Failed(envelope)) => Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                "FunTranslations error: {}",
+                envelope.message()
+            ))),
+            _ if !status.is_success() => Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                "Unexpected response status: {}",
+                status
+            ))),
+            Ok(Reply::Translated(output)) => Ok(output.translated()),
+            Err(e) => Err(request_error(e)),
+        }
+    }
+    fn rate_limited(&self, retry_after: Option<Duration>) -> Result<String, PokemonError> {
+        self.quota.exhaust(retry_after);
+        tracing::warn!(?retry_after, "FunTranslations quota exhausted");
+        Err(PokemonError::RateLimited)
     }
 }
 
-async fn parse_response(response: Response) -> anyhow::Result<String> {
-    let description = response
-        .json::<Output>()
-        .await
-        .context("Failed to parse the response")?;
-    Ok(description.translated())
+/// Read a numeric header, ignoring the missing or malformed ones.
+fn header_value<T: FromStr>(response: &Response, name: HeaderName) -> Option<T> {
+    response
+        .headers()
+        .get(name)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
 }
 
 #[cfg(test)]
@@ -65,6 +150,8 @@ mod tests {
     use wiremock::matchers::{method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    use crate::pokemon_bounded_context::adapter::out::http::RetryBudget;
+
     use super::*;
 
     #[tokio::test]
@@ -82,10 +169,14 @@ mod tests {
             .mount(&server)
             .await;
 
-        let service = FuntranslationApi::new(server.uri().parse().unwrap(), 10).unwrap();
+        let service = funtranslation_api(&server);
         assert_eq!(
             translated_description,
-            service.to_shakespeare("any_text").await.unwrap()
+            service
+                .translate(&TranslationStyle::shakespeare(), "any_text")
+                .await
+                .unwrap()
+                .text()
         );
     }
 
@@ -104,10 +195,14 @@ mod tests {
             .mount(&server)
             .await;
 
-        let service = FuntranslationApi::new(server.uri().parse().unwrap(), 10).unwrap();
+        let service = funtranslation_api(&server);
         assert_eq!(
             translated_description,
-            service.to_yoda("any_text").await.unwrap()
+            service
+                .translate(&TranslationStyle::yoda(), "any_text")
+                .await
+                .unwrap()
+                .text()
         );
     }
 
@@ -121,9 +216,15 @@ mod tests {
             .mount(&server)
             .await;
 
-        let service = FuntranslationApi::new(server.uri().parse().unwrap(), 10).unwrap();
-        assert!(service.to_yoda("any_text").await.is_err());
-        assert!(service.to_shakespeare("any_text").await.is_err());
+        let service = funtranslation_api(&server);
+        assert!(service
+            .translate(&TranslationStyle::yoda(), "any_text")
+            .await
+            .is_err());
+        assert!(service
+            .translate(&TranslationStyle::shakespeare(), "any_text")
+            .await
+            .is_err());
     }
 
     #[tokio::test]
@@ -136,9 +237,231 @@ mod tests {
             .mount(&server)
             .await;
 
-        let service = FuntranslationApi::new(server.uri().parse().unwrap(), 10).unwrap();
-        assert!(service.to_yoda("any_text").await.is_err());
-        assert!(service.to_shakespeare("any_text").await.is_err());
+        let service = funtranslation_api(&server);
+        assert!(service
+            .translate(&TranslationStyle::yoda(), "any_text")
+            .await
+            .is_err());
+        assert!(service
+            .translate(&TranslationStyle::shakespeare(), "any_text")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn funtranslation_reports_rate_limit() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let service = funtranslation_api(&server);
+        assert!(matches!(
+            service
+                .translate(&TranslationStyle::yoda(), "any_text")
+                .await,
+            Err(PokemonError::RateLimited)
+        ));
+        assert!(matches!(
+            service
+                .translate(&TranslationStyle::shakespeare(), "any_text")
+                .await,
+            Err(PokemonError::RateLimited)
+        ));
+    }
+
+    #[tokio::test]
+    async fn funtranslation_translates_to_the_configured_style_endpoint() {
+        let server = MockServer::start().await;
+        let translated_description = "translated_description";
+
+        Mock::given(method("POST"))
+            .and(path("/pirate.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(translated_valid_response(translated_description)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let service = funtranslation_api(&server);
+        assert!(service.supports(&TranslationStyle::new("Pirate")));
+        assert_eq!(
+            translated_description,
+            service
+                .translate(&TranslationStyle::new("pirate"), "any_text")
+                .await
+                .unwrap()
+                .text()
+        );
+    }
+
+    #[tokio::test]
+    async fn funtranslation_rejects_unknown_styles() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let service = funtranslation_api(&server);
+        assert!(!service.supports(&TranslationStyle::new("klingon")));
+        assert!(matches!(
+            service
+                .translate(&TranslationStyle::new("klingon"), "any_text")
+                .await,
+            Err(PokemonError::UnknownStyle(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn funtranslation_recognises_the_rate_limit_error_envelope() {
+        let server = MockServer::start().await;
+        let quota = TranslationQuota::new(5, Duration::from_secs(1));
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "60")
+                    .set_body_json(error_response(429, "Too Many Requests")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let service = funtranslation_api_with_quota(&server, quota.clone());
+        assert!(matches!(
+            service
+                .translate(&TranslationStyle::yoda(), "any_text")
+                .await,
+            Err(PokemonError::RateLimited)
+        ));
+        // the bucket refills in a second, but Retry-After still blocks the requests
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        assert_eq!(0, quota.status().remaining());
+        assert!(matches!(
+            service
+                .translate(&TranslationStyle::yoda(), "any_text")
+                .await,
+            Err(PokemonError::RateLimited)
+        ));
+    }
+
+    #[tokio::test]
+    async fn funtranslation_reports_the_error_envelope_message() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(400).set_body_json(error_response(400, "Bad Request")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let service = funtranslation_api(&server);
+        match service
+            .translate(&TranslationStyle::yoda(), "any_text")
+            .await
+        {
+            Err(PokemonError::UpstreamUnavailable(e)) => {
+                assert!(e.to_string().contains("Bad Request"))
+            }
+            _ => panic!("expected an unavailable upstream"),
+        }
+    }
+
+    #[tokio::test]
+    async fn funtranslation_stops_calling_when_the_upstream_quota_is_exhausted() {
+        let server = MockServer::start().await;
+        let quota = TranslationQuota::new(5, Duration::from_secs(3600));
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-RateLimit-Remaining", "0")
+                    .set_body_json(translated_valid_response("translated_description")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let service = funtranslation_api_with_quota(&server, quota.clone());
+        assert!(service
+            .translate(&TranslationStyle::yoda(), "any_text")
+            .await
+            .is_ok());
+        assert_eq!(0, quota.status().remaining());
+        assert!(matches!(
+            service
+                .translate(&TranslationStyle::yoda(), "any_text")
+                .await,
+            Err(PokemonError::RateLimited)
+        ));
+    }
+
+    #[tokio::test]
+    async fn funtranslation_retries_take_a_single_request_from_the_quota() {
+        let server = MockServer::start().await;
+        let quota = TranslationQuota::new(2, Duration::from_secs(3600));
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let service = funtranslation_api_with_quota(&server, quota.clone());
+        assert!(matches!(
+            service
+                .translate(&TranslationStyle::yoda(), "any_text")
+                .await,
+            Err(PokemonError::UpstreamUnavailable(_))
+        ));
+        assert_eq!(1, quota.status().remaining());
+    }
+
+    fn funtranslation_api(server: &MockServer) -> FuntranslationApi {
+        funtranslation_api_with_quota(server, TranslationQuota::new(60, Duration::from_secs(3600)))
+    }
+
+    fn funtranslation_api_with_quota(
+        server: &MockServer,
+        quota: TranslationQuota,
+    ) -> FuntranslationApi {
+        let styles = [
+            ("shakespeare", "shakespeare.json"),
+            ("yoda", "yoda.json"),
+            ("pirate", "pirate.json"),
+        ]
+        .iter()
+        .map(|(style, endpoint)| (style.to_string(), endpoint.to_string()))
+        .collect();
+        FuntranslationApi::new(
+            server.uri().parse().unwrap(),
+            10,
+            &styles,
+            quota,
+            RetryPolicy::new(
+                3,
+                Duration::from_millis(1),
+                Duration::from_millis(10),
+                0.5,
+                vec![502, 503, 504],
+                RetryBudget::new(1.0, 10),
+            ),
+        )
+        .unwrap()
+    }
+
+    fn error_response(code: u16, message: &str) -> Value {
+        json!({"error": {"code": code, "message": message}})
     }
 
     fn translated_valid_response(translated_description: &str) -> Value {
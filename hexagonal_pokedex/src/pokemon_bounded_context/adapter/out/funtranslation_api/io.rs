@@ -5,6 +5,14 @@ pub struct Input<'a> {
     pub text: &'a str,
 }
 
+/// Body of a FunTranslations response: the translation or its error envelope.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum Reply {
+    Translated(Output),
+    Failed(ErrorEnvelope),
+}
+
 #[derive(Deserialize)]
 pub struct Output {
     contents: Content,
@@ -20,3 +28,23 @@ impl Output {
         self.contents.translated
     }
 }
+
+#[derive(Deserialize)]
+pub struct ErrorEnvelope {
+    error: ErrorDetail,
+}
+
+#[derive(Deserialize)]
+struct ErrorDetail {
+    code: u16,
+    message: String,
+}
+
+impl ErrorEnvelope {
+    pub fn is_rate_limited(&self) -> bool {
+        self.error.code == 429
+    }
+    pub fn message(&self) -> &str {
+        &self.error.message
+    }
+}
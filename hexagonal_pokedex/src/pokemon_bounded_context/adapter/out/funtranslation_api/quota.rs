@@ -0,0 +1,185 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::pokemon_bounded_context::adapter::metrics::QuotaMetrics;
+
+/// Token bucket mirroring the FunTranslations quota: `limit` requests every `period`.
+///
+/// Clones share the same bucket, so the health endpoint sees what the client spends.
+#[derive(Clone)]
+pub struct TranslationQuota {
+    limit: u32,
+    period: Duration,
+    bucket: Arc<Mutex<Bucket>>,
+    metrics: Option<QuotaMetrics>,
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+    blocked_until: Option<Instant>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct QuotaStatus {
+    limit: u32,
+    remaining: u32,
+}
+
+impl TranslationQuota {
+    pub fn new(limit: u32, period: Duration) -> Self {
+        Self {
+            limit,
+            period,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: limit as f64,
+                refilled_at: Instant::now(),
+                blocked_until: None,
+            })),
+            metrics: None,
+        }
+    }
+
+    /// Publish the remaining requests in `metrics` whenever the quota is spent or synced.
+    pub fn with_metrics(self, metrics: QuotaMetrics) -> Self {
+        metrics.set_remaining(self.status().remaining());
+        Self {
+            metrics: Some(metrics),
+            ..self
+        }
+    }
+
+    /// Take a token for a request, `false` when the quota is exhausted.
+    pub fn try_acquire(&self) -> bool {
+        let now = Instant::now();
+        let acquired = self.try_acquire_at(now);
+        self.publish(now);
+        acquired
+    }
+
+    /// Align the bucket to the remaining requests reported by FunTranslations.
+    pub fn sync_remaining(&self, remaining: u32) {
+        let now = Instant::now();
+        self.bucket(now).tokens = remaining.min(self.limit) as f64;
+        self.publish(now);
+    }
+
+    /// Empty the bucket after a rejected request,
+    /// blocking every request for `retry_after` when FunTranslations tells how long to wait.
+    pub fn exhaust(&self, retry_after: Option<Duration>) {
+        let now = Instant::now();
+        {
+            let mut bucket = self.bucket(now);
+            bucket.tokens = 0.0;
+            bucket.blocked_until = retry_after.map(|retry_after| now + retry_after);
+        }
+        self.publish(now);
+    }
+
+    pub fn status(&self) -> QuotaStatus {
+        self.status_at(Instant::now())
+    }
+
+    fn try_acquire_at(&self, now: Instant) -> bool {
+        let mut bucket = self.bucket(now);
+        if bucket.blocked_until.is_some_and(|until| now < until) || bucket.tokens < 1.0 {
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+
+    fn status_at(&self, now: Instant) -> QuotaStatus {
+        let bucket = self.bucket(now);
+        let remaining = if bucket.blocked_until.is_some_and(|until| now < until) {
+            0
+        } else {
+            bucket.tokens.floor() as u32
+        };
+        QuotaStatus {
+            limit: self.limit,
+            remaining,
+        }
+    }
+
+    fn publish(&self, now: Instant) {
+        if let Some(metrics) = &self.metrics {
+            metrics.set_remaining(self.status_at(now).remaining());
+        }
+    }
+
+    /// Lock the bucket, refilled up to `now`.
+    fn bucket(&self, now: Instant) -> MutexGuard<'_, Bucket> {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        let refill = elapsed.as_secs_f64() / self.period.as_secs_f64() * self.limit as f64;
+        bucket.tokens = (bucket.tokens + refill).min(self.limit as f64);
+        bucket.refilled_at = bucket.refilled_at.max(now);
+        bucket
+    }
+}
+
+impl QuotaStatus {
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quota_stops_acquiring_once_exhausted() {
+        let quota = TranslationQuota::new(2, Duration::from_secs(3600));
+        let now = Instant::now();
+
+        assert!(quota.try_acquire_at(now));
+        assert!(quota.try_acquire_at(now));
+        assert!(!quota.try_acquire_at(now));
+        assert_eq!(0, quota.status_at(now).remaining());
+    }
+
+    #[test]
+    fn quota_refills_over_its_period() {
+        let quota = TranslationQuota::new(2, Duration::from_secs(3600));
+        let now = Instant::now();
+        quota.try_acquire_at(now);
+        quota.try_acquire_at(now);
+
+        assert_eq!(
+            1,
+            quota.status_at(now + Duration::from_secs(1800)).remaining()
+        );
+        assert_eq!(
+            QuotaStatus {
+                limit: 2,
+                remaining: 2
+            },
+            quota.status_at(now + Duration::from_secs(7200))
+        );
+    }
+
+    #[test]
+    fn quota_follows_the_upstream_remaining_requests() {
+        let quota = TranslationQuota::new(5, Duration::from_secs(3600));
+
+        quota.sync_remaining(1);
+
+        assert!(quota.try_acquire());
+        assert!(!quota.try_acquire());
+    }
+
+    #[test]
+    fn quota_is_blocked_until_retry_after() {
+        let quota = TranslationQuota::new(5, Duration::from_secs(1));
+        let now = Instant::now();
+
+        quota.exhaust(Some(Duration::from_secs(60)));
+
+        assert!(!quota.try_acquire_at(now + Duration::from_secs(30)));
+        assert!(quota.try_acquire_at(now + Duration::from_secs(61)));
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use opentelemetry::global;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Response, StatusCode};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+pub use retry::{RetryBudget, RetryPolicy};
+
+use crate::pokemon_bounded_context::domain::PokemonError;
+
+mod retry;
+
+/// Map a failed request to the upstream service to the domain error.
+pub fn request_error(error: reqwest::Error) -> PokemonError {
+    if error.is_timeout() {
+        PokemonError::UpstreamTimeout(error.into())
+    } else {
+        PokemonError::UpstreamUnavailable(error.into())
+    }
+}
+
+/// Reject the responses with an unsuccessful status.
+pub fn check_status(response: Response) -> Result<Response, PokemonError> {
+    match response.status() {
+        StatusCode::TOO_MANY_REQUESTS => Err(PokemonError::RateLimited),
+        status if status.is_success() => Ok(response),
+        status => Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+            "Unexpected response status: {}",
+            status
+        ))),
+    }
+}
+
+/// The W3C trace context of the current span, to follow the request in the upstream service.
+pub fn trace_context() -> HeaderMap {
+    let mut fields = HashMap::new();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&tracing::Span::current().context(), &mut fields)
+    });
+    fields
+        .into_iter()
+        .filter_map(|(name, value)| {
+            Some((
+                HeaderName::from_bytes(name.as_bytes()).ok()?,
+                HeaderValue::try_from(value).ok()?,
+            ))
+        })
+        .collect()
+}
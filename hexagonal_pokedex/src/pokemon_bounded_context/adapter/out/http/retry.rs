@@ -0,0 +1,309 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use reqwest::{RequestBuilder, Response};
+
+use crate::pokemon_bounded_context::adapter::metrics::UpstreamMetrics;
+use crate::pokemon_bounded_context::adapter::out::http::{request_error, trace_context};
+use crate::pokemon_bounded_context::domain::PokemonError;
+
+/// Retry of the requests to an upstream service failing with a transient error:
+/// a connection error or one of the `retry_statuses`.
+///
+/// Timeouts are not retried, since every attempt would wait the whole timeout again.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: f64,
+    retry_statuses: Vec<u16>,
+    budget: RetryBudget,
+    metrics: Option<UpstreamMetrics>,
+}
+
+/// Cap on the retries: every request earns `ratio` retries, up to `burst`.
+///
+/// It stops the retries from multiplying the load on an upstream service that is down.
+#[derive(Clone)]
+pub struct RetryBudget {
+    ratio: f64,
+    burst: f64,
+    balance: Arc<Mutex<f64>>,
+}
+
+impl RetryPolicy {
+    /// The delay before the n-th retry is `base_delay * 2^(n-1)`, capped at `max_delay`
+    /// and reduced by a random fraction up to `jitter` (from 0 to 1).
+    pub fn new(
+        max_attempts: u32,
+        base_delay: Duration,
+        max_delay: Duration,
+        jitter: f64,
+        retry_statuses: Vec<u16>,
+        budget: RetryBudget,
+    ) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+            max_delay,
+            jitter: jitter.clamp(0.0, 1.0),
+            retry_statuses,
+            budget,
+            metrics: None,
+        }
+    }
+
+    /// Record the latency and the failures of every attempt in `metrics`.
+    pub fn with_metrics(self, metrics: UpstreamMetrics) -> Self {
+        Self {
+            metrics: Some(metrics),
+            ..self
+        }
+    }
+
+    /// Send the request built by `request` until it succeeds or can no longer be retried,
+    /// returning the last response.
+    ///
+    /// Every attempt carries the trace context of the current span,
+    /// which records the number of attempts and the last response status.
+    #[tracing::instrument(
+        name = "Calling the upstream service",
+        skip(self, request),
+        fields(attempts, status)
+    )]
+    pub async fn execute<F>(&self, mut request: F) -> Result<Response, PokemonError>
+    where
+        F: FnMut() -> Result<RequestBuilder, PokemonError>,
+    {
+        self.budget.deposit();
+        let span = tracing::Span::current();
+        let mut attempt = 1;
+        loop {
+            let started_at = Instant::now();
+            let outcome = request()?.headers(trace_context()).send().await;
+            span.record("attempts", &attempt);
+            if let Ok(response) = &outcome {
+                span.record("status", &response.status().as_u16());
+            }
+            if let Some(metrics) = &self.metrics {
+                metrics.observe(started_at);
+                match &outcome {
+                    Ok(response) if !response.status().is_success() => {
+                        metrics.error(response.status().as_str())
+                    }
+                    Ok(_) => {}
+                    Err(error) if error.is_timeout() => metrics.error("timeout"),
+                    Err(_) => metrics.error("connection"),
+                }
+            }
+            let failure = match &outcome {
+                Ok(response) if self.retry_statuses.contains(&response.status().as_u16()) => {
+                    format!("response status {}", response.status())
+                }
+                Err(error) if !error.is_timeout() => error.to_string(),
+                _ => return outcome.map_err(request_error),
+            };
+            if attempt >= self.max_attempts || !self.budget.try_withdraw() {
+                return outcome.map_err(request_error);
+            }
+            let delay = self.delay(attempt);
+            tracing::warn!(
+                attempt,
+                ?delay,
+                "Retrying the request to the upstream service after: {}",
+                failure
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(attempt - 1))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        exponential.mul_f64(1.0 - self.jitter * rand::thread_rng().gen::<f64>())
+    }
+}
+
+impl RetryBudget {
+    pub fn new(ratio: f64, burst: u32) -> Self {
+        Self {
+            ratio,
+            burst: burst as f64,
+            balance: Arc::new(Mutex::new(burst as f64)),
+        }
+    }
+    fn deposit(&self) {
+        let mut balance = self.balance.lock().unwrap_or_else(|e| e.into_inner());
+        *balance = (*balance + self.ratio).min(self.burst);
+    }
+    fn try_withdraw(&self) -> bool {
+        let mut balance = self.balance.lock().unwrap_or_else(|e| e.into_inner());
+        if *balance < 1.0 {
+            return false;
+        }
+        *balance -= 1.0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Client;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::pokemon_bounded_context::adapter::metrics::Metrics;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn retry_policy_retries_transient_statuses_until_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = retry_policy(3, RetryBudget::new(1.0, 10))
+            .execute(|| Ok(Client::new().get(server.uri())))
+            .await
+            .unwrap();
+        assert_eq!(200, response.status().as_u16());
+    }
+
+    #[tokio::test]
+    async fn retry_policy_returns_the_last_response_when_attempts_are_exhausted() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let response = retry_policy(3, RetryBudget::new(1.0, 10))
+            .execute(|| Ok(Client::new().get(server.uri())))
+            .await
+            .unwrap();
+        assert_eq!(503, response.status().as_u16());
+    }
+
+    #[tokio::test]
+    async fn retry_policy_does_not_retry_other_statuses() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = retry_policy(3, RetryBudget::new(1.0, 10))
+            .execute(|| Ok(Client::new().get(server.uri())))
+            .await
+            .unwrap();
+        assert_eq!(500, response.status().as_u16());
+    }
+
+    #[tokio::test]
+    async fn retry_policy_stops_retrying_when_the_budget_is_exhausted() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        // one retry in the burst and none earned by the requests
+        let policy = retry_policy(3, RetryBudget::new(0.0, 1));
+        for _ in 0..2 {
+            policy
+                .execute(|| Ok(Client::new().get(server.uri())))
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_policy_retries_connection_errors() {
+        let mut attempts = 0;
+
+        let result = retry_policy(3, RetryBudget::new(1.0, 10))
+            .execute(|| {
+                attempts += 1;
+                Ok(Client::new().get("http://127.0.0.1:1/"))
+            })
+            .await;
+        assert!(matches!(result, Err(PokemonError::UpstreamUnavailable(_))));
+        assert_eq!(3, attempts);
+    }
+
+    #[test]
+    fn retry_policy_delay_grows_exponentially_up_to_the_maximum() {
+        let policy = RetryPolicy::new(
+            5,
+            Duration::from_millis(100),
+            Duration::from_millis(300),
+            0.0,
+            vec![],
+            RetryBudget::new(1.0, 10),
+        );
+        assert_eq!(Duration::from_millis(100), policy.delay(1));
+        assert_eq!(Duration::from_millis(200), policy.delay(2));
+        assert_eq!(Duration::from_millis(300), policy.delay(3));
+    }
+
+    #[tokio::test]
+    async fn retry_policy_records_every_attempt_in_the_metrics() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let metrics = Metrics::new().unwrap();
+        retry_policy(3, RetryBudget::new(1.0, 10))
+            .with_metrics(metrics.upstream("poke_api"))
+            .execute(|| Ok(Client::new().get(server.uri())))
+            .await
+            .unwrap();
+
+        let rendered = metrics.render().unwrap();
+        assert!(rendered
+            .contains("pokedex_upstream_request_duration_seconds_count{upstream=\"poke_api\"} 2"));
+        assert!(rendered
+            .contains("pokedex_upstream_errors_total{error=\"502\",upstream=\"poke_api\"} 1"));
+    }
+
+    fn retry_policy(max_attempts: u32, budget: RetryBudget) -> RetryPolicy {
+        RetryPolicy::new(
+            max_attempts,
+            Duration::from_millis(1),
+            Duration::from_millis(10),
+            0.5,
+            vec![502, 503, 504],
+            budget,
+        )
+    }
+}
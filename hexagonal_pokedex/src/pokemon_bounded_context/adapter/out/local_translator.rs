@@ -0,0 +1,91 @@
+use crate::pokemon_bounded_context::adapter::out::local_translator::shakespeare::Dictionary;
+use crate::pokemon_bounded_context::adapter::out::local_translator::yoda::to_yoda;
+use crate::pokemon_bounded_context::domain::{
+    PokemonError, Translated, TranslationSource, TranslationStyle,
+};
+use crate::pokemon_bounded_context::port::out::Translator;
+
+mod shakespeare;
+mod yoda;
+
+/// Rule based yoda and shakespeare translators, that need no network.
+pub struct LocalTranslator {
+    shakespeare_dictionary: Dictionary,
+}
+
+impl LocalTranslator {
+    pub fn new() -> Self {
+        LocalTranslator {
+            shakespeare_dictionary: Dictionary::new(),
+        }
+    }
+}
+
+impl Default for LocalTranslator {
+    fn default() -> Self {
+        LocalTranslator::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl Translator for LocalTranslator {
+    fn supports(&self, style: &TranslationStyle) -> bool {
+        *style == TranslationStyle::yoda() || *style == TranslationStyle::shakespeare()
+    }
+    fn source(&self, _style: &TranslationStyle) -> TranslationSource {
+        TranslationSource::Local
+    }
+    #[tracing::instrument(name = "Translating locally", skip(self, text), fields(style = %style))]
+    async fn translate(
+        &self,
+        style: &TranslationStyle,
+        text: &str,
+    ) -> Result<Translated, PokemonError> {
+        let translation = if *style == TranslationStyle::yoda() {
+            to_yoda(text)
+        } else if *style == TranslationStyle::shakespeare() {
+            self.shakespeare_dictionary.translate(text)
+        } else {
+            return Err(PokemonError::UnknownStyle(style.to_string()));
+        };
+        Ok(Translated::new(translation, TranslationSource::Local))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn local_translator_translates_yoda_and_shakespeare() {
+        let translator = LocalTranslator::new();
+        assert_eq!(
+            "Hungry, it is.",
+            translator
+                .translate(&TranslationStyle::yoda(), "It is hungry.")
+                .await
+                .unwrap()
+                .text()
+        );
+        assert_eq!(
+            "'Tis hungry.",
+            translator
+                .translate(&TranslationStyle::shakespeare(), "It is hungry.")
+                .await
+                .unwrap()
+                .text()
+        );
+    }
+
+    #[tokio::test]
+    async fn local_translator_rejects_other_styles() {
+        let translator = LocalTranslator::new();
+        assert!(!translator.supports(&TranslationStyle::new("pirate")));
+        assert!(matches!(
+            translator
+                .translate(&TranslationStyle::new("pirate"), "any_text")
+                .await,
+            Err(PokemonError::UnknownStyle(_))
+        ));
+    }
+}
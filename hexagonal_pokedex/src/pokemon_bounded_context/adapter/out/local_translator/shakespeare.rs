@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+const DICTIONARY: &str = include_str!("shakespeare.txt");
+
+/// Word substitution dictionary, keyed by the lowercase modern words.
+pub struct Dictionary {
+    phrases: HashMap<Vec<String>, String>,
+    longest_phrase: usize,
+}
+
+impl Dictionary {
+    pub fn new() -> Self {
+        let phrases: HashMap<Vec<String>, String> = DICTIONARY
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let (modern, replacement) = line.split_once('=')?;
+                Some((
+                    modern.split_whitespace().map(str::to_lowercase).collect(),
+                    replacement.trim().to_string(),
+                ))
+            })
+            .collect();
+        let longest_phrase = phrases.keys().map(Vec::len).max().unwrap_or(1);
+        Dictionary {
+            phrases,
+            longest_phrase,
+        }
+    }
+
+    /// Replace the modern words, keeping the punctuation around them
+    /// and the capitalisation of the first letter.
+    pub fn translate(&self, text: &str) -> String {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let mut translated = Vec::with_capacity(words.len());
+        let mut index = 0;
+        while index < words.len() {
+            match self.longest_match(&words[index..]) {
+                Some((length, replacement)) => {
+                    let (leading, first, _) = split_punctuation(words[index]);
+                    let (_, _, trailing) = split_punctuation(words[index + length - 1]);
+                    translated.push(format!(
+                        "{}{}{}",
+                        leading,
+                        with_capitalisation_of(first, replacement),
+                        trailing
+                    ));
+                    index += length;
+                }
+                None => {
+                    translated.push(words[index].to_string());
+                    index += 1;
+                }
+            }
+        }
+        translated.join(" ")
+    }
+
+    fn longest_match(&self, words: &[&str]) -> Option<(usize, &str)> {
+        (1..=self.longest_phrase.min(words.len()))
+            .rev()
+            .find_map(|length| {
+                let phrase = &words[..length];
+                // punctuation can only surround the phrase, not split it
+                let is_contiguous = phrase[..length - 1]
+                    .iter()
+                    .all(|word| split_punctuation(word).2.is_empty())
+                    && phrase[1..]
+                        .iter()
+                        .all(|word| split_punctuation(word).0.is_empty());
+                if !is_contiguous {
+                    return None;
+                }
+                let key: Vec<String> = phrase
+                    .iter()
+                    .map(|word| split_punctuation(word).1.to_lowercase())
+                    .collect();
+                self.phrases
+                    .get(&key)
+                    .map(|replacement| (length, replacement.as_str()))
+            })
+    }
+}
+
+/// Split the word into leading punctuation, the word itself and trailing punctuation.
+fn split_punctuation(word: &str) -> (&str, &str, &str) {
+    let start = word
+        .find(|c: char| c.is_alphanumeric())
+        .unwrap_or(word.len());
+    let end = word
+        .rfind(|c: char| c.is_alphanumeric())
+        .map_or(start, |i| {
+            i + word[i..].chars().next().map_or(1, char::len_utf8)
+        });
+    (&word[..start], &word[start..end], &word[end..])
+}
+
+/// Capitalise the first letter of `replacement` when `original` starts with a capital,
+/// skipping leading punctuation such as the apostrophe of `'tis`.
+fn with_capitalisation_of(original: &str, replacement: &str) -> String {
+    if !original.chars().next().is_some_and(char::is_uppercase) {
+        return replacement.to_string();
+    }
+    match replacement.find(char::is_alphabetic) {
+        Some(index) => {
+            let (prefix, rest) = replacement.split_at(index);
+            let mut chars = rest.chars();
+            chars
+                .next()
+                .map(|first| {
+                    prefix
+                        .chars()
+                        .chain(first.to_uppercase())
+                        .chain(chars)
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+        None => replacement.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dictionary_replaces_words_keeping_punctuation_and_capitalisation() {
+        assert_eq!(
+            "Thou hath naught to fear; 'tis thy foe.",
+            Dictionary::new().translate("You has nothing to fear; it is your enemy.")
+        );
+    }
+
+    #[test]
+    fn dictionary_does_not_join_phrases_across_punctuation() {
+        assert_eq!(
+            "Here it, is a pokemon",
+            Dictionary::new().translate("Here it, is a pokemon")
+        );
+    }
+}
@@ -0,0 +1,100 @@
+/// Words after which the rest of a sentence is moved to its beginning:
+/// "It was created by a scientist." becomes "Created by a scientist, it was."
+const AUXILIARIES: &[&str] = &[
+    "am", "are", "can", "could", "did", "does", "had", "has", "have", "is", "may", "might", "must",
+    "should", "was", "were", "will", "would",
+];
+
+/// Reorder every sentence of the text as yoda would.
+pub fn to_yoda(text: &str) -> String {
+    sentences(text)
+        .iter()
+        .map(|sentence| reorder(sentence))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Split the text after every `.`, `!` or `?` that ends a word.
+fn sentences(text: &str) -> Vec<Vec<&str>> {
+    let mut sentences = vec![];
+    let mut sentence = vec![];
+    for word in text.split_whitespace() {
+        sentence.push(word);
+        if word.ends_with(['.', '!', '?']) {
+            sentences.push(std::mem::take(&mut sentence));
+        }
+    }
+    if !sentence.is_empty() {
+        sentences.push(sentence);
+    }
+    sentences
+}
+
+fn reorder(words: &[&str]) -> String {
+    let last = words.len().saturating_sub(1);
+    let auxiliary = words.iter().enumerate().position(|(index, word)| {
+        index > 0 && index < last && AUXILIARIES.contains(&word.to_lowercase().as_str())
+    });
+    match auxiliary {
+        None => words.join(" "),
+        Some(auxiliary) => {
+            let rest = words[auxiliary + 1..].join(" ");
+            let rest_without_ending = rest.trim_end_matches(['.', '!', '?']);
+            let ending = &rest[rest_without_ending.len()..];
+            let subject: Vec<String> = words[..=auxiliary]
+                .iter()
+                .enumerate()
+                .map(|(index, word)| match index {
+                    0 if *word != "I" => lowercase_first(word),
+                    _ => word.to_string(),
+                })
+                .collect();
+            format!(
+                "{}, {}{}",
+                uppercase_first(rest_without_ending.trim_end_matches(',')),
+                subject.join(" "),
+                ending
+            )
+        }
+    }
+}
+
+fn uppercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_yoda_moves_the_rest_of_the_sentence_first() {
+        assert_eq!(
+            "Created by a scientist after years of gene splicing, it was. \
+             Its own cave, it has!",
+            to_yoda(
+                "It was created by a scientist after years\nof gene splicing. It has its own cave!"
+            )
+        );
+    }
+
+    #[test]
+    fn to_yoda_keeps_sentences_without_auxiliaries() {
+        assert_eq!(
+            "Pikachu sleeps. Strong, I am.",
+            to_yoda("Pikachu sleeps. I am strong.")
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use lru::LruCache;
+
+use crate::pokemon_bounded_context::adapter::out::ExpiringCache;
+use crate::pokemon_bounded_context::domain::{CacheKey, PokemonError};
+use crate::pokemon_bounded_context::port::out::{CacheRetrieval, CacheUpdater};
+
+/// In-process cache bounded to `capacity` entries, evicting the least recently used ones.
+#[derive(Clone)]
+pub struct MemoryCache {
+    entries: Arc<Mutex<LruCache<String, (String, Instant)>>>,
+    ttl: Duration,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize, ttl_seconds: u64) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(LruCache::new(capacity))),
+            ttl: Duration::from_secs(ttl_seconds),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheRetrieval for MemoryCache {
+    async fn get(&self, key: &CacheKey) -> Result<Option<String>, PokemonError> {
+        Ok(self.get_with_ttl(key).await?.map(|(value, _)| value))
+    }
+}
+
+#[async_trait::async_trait]
+impl ExpiringCache for MemoryCache {
+    async fn get_with_ttl(
+        &self,
+        key: &CacheKey,
+    ) -> Result<Option<(String, Option<Duration>)>, PokemonError> {
+        let key = key.to_string();
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        match entries.get(&key) {
+            Some((value, expiration)) if *expiration > now => {
+                Ok(Some((value.clone(), Some(*expiration - now))))
+            }
+            Some(_) => {
+                entries.pop(&key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheUpdater for MemoryCache {
+    async fn update(
+        &self,
+        key: &CacheKey,
+        pokemon_description: String,
+    ) -> Result<(), PokemonError> {
+        self.update_with_ttl(key, pokemon_description, self.ttl)
+            .await
+    }
+    async fn update_with_ttl(
+        &self,
+        key: &CacheKey,
+        value: String,
+        ttl: Duration,
+    ) -> Result<(), PokemonError> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .put(key.to_string(), (value, Instant::now() + ttl));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pokemon_bounded_context::domain::TranslationStyle;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn memory_cache_retrieves_updated_value() {
+        let cache = MemoryCache::new(10, 60);
+        cache
+            .update(&key("mewtwo"), "description".to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            Some("description".to_string()),
+            cache.get(&key("mewtwo")).await.unwrap()
+        );
+        assert_eq!(None, cache.get(&key("pikachu")).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn memory_cache_evicts_least_recently_used_entry() {
+        let cache = MemoryCache::new(2, 60);
+        cache.update(&key("mewtwo"), "m".to_string()).await.unwrap();
+        cache
+            .update(&key("pikachu"), "p".to_string())
+            .await
+            .unwrap();
+        cache.get(&key("mewtwo")).await.unwrap();
+        cache.update(&key("eevee"), "e".to_string()).await.unwrap();
+
+        assert_eq!(None, cache.get(&key("pikachu")).await.unwrap());
+        assert!(cache.get(&key("mewtwo")).await.unwrap().is_some());
+        assert!(cache.get(&key("eevee")).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn memory_cache_expires_entries_with_their_own_ttl() {
+        let cache = MemoryCache::new(10, 60);
+        cache
+            .update_with_ttl(&key("mewtwo"), "m".to_string(), Duration::from_secs(0))
+            .await
+            .unwrap();
+        assert_eq!(None, cache.get(&key("mewtwo")).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn memory_cache_expires_entries() {
+        let cache = MemoryCache::new(10, 0);
+        cache.update(&key("mewtwo"), "m".to_string()).await.unwrap();
+        assert_eq!(None, cache.get(&key("mewtwo")).await.unwrap());
+    }
+
+    fn key(pokemon_name: &str) -> CacheKey {
+        CacheKey::translation(pokemon_name.to_string(), TranslationStyle::yoda(), None, 1)
+    }
+}
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use crate::pokemon_bounded_context::adapter::metrics::CacheMetrics;
+use crate::pokemon_bounded_context::domain::{CacheKey, PokemonError};
+use crate::pokemon_bounded_context::port::out::{CacheRetrieval, CacheUpdater};
+
+/// Decorator counting the hits, misses and failures of the `inner` cache.
+pub struct MeteredCache<C> {
+    inner: C,
+    metrics: CacheMetrics,
+}
+
+impl<C> MeteredCache<C> {
+    pub fn new(inner: C, metrics: CacheMetrics) -> Self {
+        Self { inner, metrics }
+    }
+
+    fn record_failure<T>(&self, result: Result<T, PokemonError>) -> Result<T, PokemonError> {
+        if result.is_err() {
+            self.metrics.error();
+        }
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> CacheRetrieval for MeteredCache<C>
+where
+    C: CacheRetrieval + Send + Sync,
+{
+    async fn get(&self, key: &CacheKey) -> Result<Option<String>, PokemonError> {
+        let value = self.record_failure(self.inner.get(key).await)?;
+        match value {
+            Some(_) => self.metrics.hit(),
+            None => self.metrics.miss(),
+        }
+        Ok(value)
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> CacheUpdater for MeteredCache<C>
+where
+    C: CacheUpdater + Send + Sync,
+{
+    async fn update(&self, key: &CacheKey, value: String) -> Result<(), PokemonError> {
+        self.record_failure(self.inner.update(key, value).await)
+    }
+    async fn update_with_ttl(
+        &self,
+        key: &CacheKey,
+        value: String,
+        ttl: Duration,
+    ) -> Result<(), PokemonError> {
+        self.record_failure(self.inner.update_with_ttl(key, value, ttl).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pokemon_bounded_context::adapter::metrics::Metrics;
+    use crate::pokemon_bounded_context::domain::TranslationStyle;
+    use crate::pokemon_bounded_context::port::out::MockCacheRetrieval;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn metered_cache_counts_hits_misses_and_errors() {
+        let mut cache = MockCacheRetrieval::new();
+        let mut values = vec![
+            Ok(Some("cached".to_string())),
+            Ok(None),
+            Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!("down"))),
+        ]
+        .into_iter();
+        cache
+            .expect_get()
+            .times(3)
+            .returning(move |_| values.next().unwrap());
+
+        let metrics = Metrics::new().unwrap();
+        let metered_cache = MeteredCache::new(cache, metrics.translation_cache());
+        let key = CacheKey::translation("pikachu".to_string(), TranslationStyle::yoda(), None, 1);
+        for _ in 0..3 {
+            let _ = metered_cache.get(&key).await;
+        }
+
+        let rendered = metrics.render().unwrap();
+        for outcome in &["hit", "miss", "error"] {
+            assert!(rendered.contains(&format!(
+                "pokedex_translation_cache_total{{outcome=\"{}\"}} 1",
+                outcome
+            )));
+        }
+    }
+}
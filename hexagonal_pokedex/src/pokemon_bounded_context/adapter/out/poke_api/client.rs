@@ -5,67 +5,160 @@ use anyhow::Context;
 use graphql_client::{GraphQLQuery, Response};
 use reqwest::{Client, Url};
 
-use crate::pokemon_bounded_context::adapter::out::poke_api::io::GqlPokemon;
-use crate::pokemon_bounded_context::adapter::out::poke_api::io::GqlPokemonResponse;
-use crate::pokemon_bounded_context::adapter::out::poke_api::io::GqlPokemonVariables;
-use crate::pokemon_bounded_context::domain::Pokemon;
-use crate::pokemon_bounded_context::port::out::PokemonRetrieval;
-
+use crate::pokemon_bounded_context::adapter::out::http::{
+    check_status, request_error, RetryPolicy,
+};
+use crate::pokemon_bounded_context::adapter::out::poke_api::io::{
+    into_pokemons, into_species_names, into_species_page, species_page_variables,
+    GqlEvolutionChain, GqlEvolutionChainVariables, GqlPokemon, GqlPokemonVariables,
+    GqlSpeciesNames, GqlSpeciesNamesVariables, GqlSpeciesPageQuery,
+};
+use crate::pokemon_bounded_context::domain::{
+    EvolutionChain, Language, PageRequest, Pokemon, PokemonError, PokemonLookup, SpeciesFilter,
+    SpeciesSummary,
+};
+use crate::pokemon_bounded_context::port::out::{
+    EvolutionRetrieval, PokemonRetrieval, SpeciesListing, SpeciesRetrieval,
+};
+
+#[derive(Clone)]
 pub struct PokeApi {
     client: Client,
     url: Url,
+    retry_policy: RetryPolicy,
 }
 
 #[async_trait::async_trait]
 impl PokemonRetrieval for PokeApi {
-    async fn get(&self, pokemon_name: String) -> anyhow::Result<Pokemon> {
-        self.execute_gql_pokemon_query(pokemon_name)
-            .await
-            .and_then(TryInto::try_into)
+    #[tracing::instrument(
+        name = "Retrieving pokemon from PokeAPI",
+        skip(self, lookup, languages),
+        fields(pokemon_name = %lookup)
+    )]
+    async fn get(
+        &self,
+        lookup: PokemonLookup,
+        languages: Vec<Language>,
+    ) -> Result<Pokemon, PokemonError> {
+        let (names, ids) = match lookup {
+            PokemonLookup::ByName(name) => (vec![name], vec![]),
+            PokemonLookup::ById(id) => (vec![], vec![id.into()]),
+        };
+        let response = self
+            .execute_gql_query::<GqlPokemon>(GqlPokemonVariables {
+                names,
+                ids,
+                languages: languages.iter().map(ToString::to_string).collect(),
+            })
+            .await?;
+        (response, languages.as_slice()).try_into()
+    }
+    #[tracing::instrument(
+        name = "Retrieving many pokemon from PokeAPI",
+        skip(self, pokemon_names, languages),
+        fields(pokemon_count = pokemon_names.len())
+    )]
+    async fn get_many(
+        &self,
+        pokemon_names: Vec<String>,
+        languages: Vec<Language>,
+    ) -> Result<Vec<Pokemon>, PokemonError> {
+        let response = self
+            .execute_gql_query::<GqlPokemon>(GqlPokemonVariables {
+                names: pokemon_names,
+                ids: vec![],
+                languages: languages.iter().map(ToString::to_string).collect(),
+            })
+            .await?;
+        into_pokemons(response, &languages)
+    }
+}
+
+#[async_trait::async_trait]
+impl EvolutionRetrieval for PokeApi {
+    #[tracing::instrument(
+        name = "Retrieving pokemon evolutions from PokeAPI",
+        skip(self, pokemon_name),
+        fields(pokemon_name = %pokemon_name)
+    )]
+    async fn get_evolutions(&self, pokemon_name: String) -> Result<EvolutionChain, PokemonError> {
+        self.execute_gql_query::<GqlEvolutionChain>(GqlEvolutionChainVariables {
+            name: pokemon_name,
+        })
+        .await?
+        .try_into()
+    }
+}
+
+#[async_trait::async_trait]
+impl SpeciesRetrieval for PokeApi {
+    #[tracing::instrument(name = "Retrieving species names from PokeAPI", skip(self))]
+    async fn get_names(&self) -> Result<Vec<String>, PokemonError> {
+        into_species_names(
+            self.execute_gql_query::<GqlSpeciesNames>(GqlSpeciesNamesVariables {})
+                .await?,
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl SpeciesListing for PokeApi {
+    #[tracing::instrument(name = "Listing species from PokeAPI", skip(self, filter, page))]
+    async fn list(
+        &self,
+        filter: SpeciesFilter,
+        page: PageRequest,
+    ) -> Result<(Vec<SpeciesSummary>, u64), PokemonError> {
+        into_species_page(
+            self.execute_gql_query::<GqlSpeciesPageQuery>(species_page_variables(&filter, &page))
+                .await?,
+        )
     }
 }
 
 impl PokeApi {
-    pub fn new(url: Url, timeout_second: u64) -> anyhow::Result<Self> {
+    pub fn new(url: Url, timeout_second: u64, retry_policy: RetryPolicy) -> anyhow::Result<Self> {
         Ok(Self {
             client: Client::builder()
                 .timeout(Duration::from_secs(timeout_second))
                 .build()
                 .context(format!("Error creating client with:\nurl: {}", url,))?,
             url,
+            retry_policy,
         })
     }
 
-    async fn execute_gql_pokemon_query(
+    #[tracing::instrument(name = "Querying PokeAPI", skip(self, variables), fields(operation))]
+    async fn execute_gql_query<Q: GraphQLQuery>(
         &self,
-        name: String,
-    ) -> anyhow::Result<Response<GqlPokemonResponse>> {
-        let request_body = GqlPokemon::build_query(GqlPokemonVariables { name });
+        variables: Q::Variables,
+    ) -> Result<Response<Q::ResponseData>, PokemonError> {
+        let request_body = Q::build_query(variables);
+        tracing::Span::current().record("operation", &request_body.operation_name);
 
         let response = self
-            .client
-            .post(self.url.as_str())
-            .json(&request_body)
-            .header("Content-Type", "application/json")
-            .send()
-            .await
-            .context("Failed to send request")?;
-
-        let graphql_response = response
-            .json()
-            .await
-            .context("Failed to serialize graphql response")?;
-
-        Ok(graphql_response)
+            .retry_policy
+            .execute(|| {
+                Ok(self
+                    .client
+                    .post(self.url.as_str())
+                    .json(&request_body)
+                    .header("Content-Type", "application/json"))
+            })
+            .await?;
+
+        check_status(response)?.json().await.map_err(request_error)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use serde_json::{json, Value};
-    use wiremock::matchers::method;
+    use wiremock::matchers::{body_partial_json, method};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    use crate::pokemon_bounded_context::adapter::out::http::RetryBudget;
+
     use super::*;
 
     #[tokio::test]
@@ -82,7 +175,7 @@ mod tests {
                 ResponseTemplate::new(200).set_body_json(build_pokeapi_response(
                     pokemon_name,
                     Some(habitat),
-                    &[description],
+                    &[(description, "en")],
                     is_legendary,
                 )),
             )
@@ -90,19 +183,25 @@ mod tests {
             .mount(&server)
             .await;
 
-        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10).unwrap();
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
 
         let correct_response = json!({
+            "id": 150,
             "name": pokemon_name,
             "habitat": habitat,
             "description":description,
-            "isLegendary":is_legendary
+            "isLegendary":is_legendary,
+            "isMythical":false,
+            "language":"en"
 
         });
 
         assert_eq!(
             correct_response,
-            json!(poke_api.get(pokemon_name.to_string()).await.unwrap())
+            json!(poke_api
+                .get(by_name(pokemon_name), english())
+                .await
+                .unwrap())
         );
     }
 
@@ -126,17 +225,131 @@ mod tests {
             .mount(&server)
             .await;
 
-        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10).unwrap();
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
 
         let correct_response = json!({
+            "id": 150,
             "name": pokemon_name,
-            "isLegendary":is_legendary
+            "isLegendary":is_legendary,
+            "isMythical":false
+        });
+
+        assert_eq!(
+            correct_response,
+            json!(poke_api
+                .get(by_name(pokemon_name), english())
+                .await
+                .unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn pokeapi_successfully_parses_details_of_the_default_variety() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!(
+                {
+                    "data":{
+                        "info":[
+                            {
+                                "id": 151,
+                                "name": "mew",
+                                "descriptions": [],
+                                "is_legendary": false,
+                                "is_mythical": true,
+                                "generation": { "name": "generation-i" },
+                                "varieties": [
+                                    {
+                                        "height": 4,
+                                        "weight": 40,
+                                        "types": [{ "pokemon_type": { "name": "psychic" } }],
+                                        "stats": [
+                                            { "base_stat": 100, "stat": { "name": "hp" } },
+                                            { "base_stat": 100, "stat": { "name": "attack" } }
+                                        ],
+                                        "abilities": [
+                                            { "is_hidden": false, "ability": { "name": "synchronize" } }
+                                        ]
+                                    }
+                                ]
+                            }
+                        ]
+                    }
+                }
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+
+        assert_eq!(
+            json!({
+                "id": 151,
+                "name": "mew",
+                "isLegendary": false,
+                "isMythical": true,
+                "generation": "generation-i",
+                "height": 4,
+                "weight": 40,
+                "types": ["psychic"],
+                "stats": [
+                    { "name": "hp", "baseStat": 100 },
+                    { "name": "attack", "baseStat": 100 }
+                ],
+                "abilities": [{ "name": "synchronize", "isHidden": false }]
+            }),
+            json!(poke_api.get(by_name("mew"), english()).await.unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn pokeapi_returns_description_in_the_first_available_language() {
+        let server = MockServer::start().await;
 
+        let pokemon_name = "mewtwo";
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "variables": { "languages": ["fr-CA", "fr", "en"] }
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(build_pokeapi_response(
+                    pokemon_name,
+                    None,
+                    &[("english_description", "en"), ("french_description", "fr")],
+                    true,
+                )),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+
+        let correct_response = json!({
+            "id": 150,
+            "name": pokemon_name,
+            "description": "french_description",
+            "isLegendary": true,
+            "isMythical": false,
+            "language": "fr"
         });
 
         assert_eq!(
             correct_response,
-            json!(poke_api.get(pokemon_name.to_string()).await.unwrap())
+            json!(poke_api
+                .get(
+                    by_name(pokemon_name),
+                    vec![
+                        Language::new("fr-CA"),
+                        Language::new("fr"),
+                        Language::new("en")
+                    ]
+                )
+                .await
+                .unwrap())
         );
     }
 
@@ -156,9 +369,12 @@ mod tests {
             .mount(&server)
             .await;
 
-        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10).unwrap();
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
 
-        assert!(&poke_api.get("any_pokemon".into()).await.is_err());
+        assert!(matches!(
+            poke_api.get(by_name("any_pokemon"), english()).await,
+            Err(PokemonError::NotFound)
+        ));
     }
 
     #[tokio::test]
@@ -177,9 +393,12 @@ mod tests {
             .mount(&server)
             .await;
 
-        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10).unwrap();
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
 
-        assert!(poke_api.get("any_pokemon".into()).await.is_err());
+        assert!(matches!(
+            poke_api.get(by_name("any_pokemon"), english()).await,
+            Err(PokemonError::UpstreamUnavailable(_))
+        ));
     }
 
     #[tokio::test]
@@ -196,8 +415,11 @@ mod tests {
             .mount(&server)
             .await;
 
-        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10).unwrap();
-        assert!(poke_api.get("any_pokemon".into()).await.is_err());
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+        assert!(matches!(
+            poke_api.get(by_name("any_pokemon"), english()).await,
+            Err(PokemonError::UpstreamUnavailable(_))
+        ));
     }
 
     #[tokio::test]
@@ -213,8 +435,16 @@ mod tests {
             .mount(&server)
             .await;
 
-        let poke_api = PokeApi::new(server.uri().parse().unwrap(), timeout_seconds).unwrap();
-        assert!(poke_api.get("any_pokemon".into()).await.is_err());
+        let poke_api = PokeApi::new(
+            server.uri().parse().unwrap(),
+            timeout_seconds,
+            retry_policy(),
+        )
+        .unwrap();
+        assert!(matches!(
+            poke_api.get(by_name("any_pokemon"), english()).await,
+            Err(PokemonError::UpstreamTimeout(_))
+        ));
     }
 
     #[tokio::test]
@@ -228,19 +458,358 @@ mod tests {
             .mount(&server)
             .await;
 
-        let poke_api = PokeApi::new(server.uri().parse().unwrap(), timeout_seconds).unwrap();
-        assert!(poke_api.get("any_pokemon".into()).await.is_err());
+        let poke_api = PokeApi::new(
+            server.uri().parse().unwrap(),
+            timeout_seconds,
+            retry_policy(),
+        )
+        .unwrap();
+        assert!(matches!(
+            poke_api.get(by_name("any_pokemon"), english()).await,
+            Err(PokemonError::UpstreamUnavailable(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn pokeapi_handles_correctly_rate_limit() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+        assert!(matches!(
+            poke_api.get(by_name("any_pokemon"), english()).await,
+            Err(PokemonError::RateLimited)
+        ));
+    }
+
+    #[tokio::test]
+    async fn pokeapi_retrieves_many_pokemon_in_a_single_request() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "variables": { "names": ["mewtwo", "pikachu", "missingno"] }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!(
+                {
+                    "data":{
+                        "info":[
+                            {
+                                "id": 150,
+                                "name": "mewtwo",
+                                "descriptions": [],
+                                "is_legendary": true,
+                                "is_mythical": false,
+                                "varieties": []
+                            },
+                            {
+                                "id": 25,
+                                "name": "pikachu",
+                                "descriptions": [],
+                                "is_legendary": false,
+                                "is_mythical": false,
+                                "varieties": []
+                            }
+                        ]
+                    }
+                }
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+
+        assert_eq!(
+            json!([
+                { "name": "mewtwo", "id": 150, "isLegendary": true, "isMythical": false },
+                { "name": "pikachu", "id": 25, "isLegendary": false, "isMythical": false }
+            ]),
+            json!(poke_api
+                .get_many(
+                    vec![
+                        "mewtwo".to_string(),
+                        "pikachu".to_string(),
+                        "missingno".to_string()
+                    ],
+                    english()
+                )
+                .await
+                .unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn pokeapi_retrieves_the_pokemon_by_id() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "variables": { "names": [], "ids": [150] }
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(build_pokeapi_response(
+                    "mewtwo",
+                    None,
+                    &[],
+                    true,
+                )),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+        let pokemon = poke_api
+            .get(PokemonLookup::ById(150), english())
+            .await
+            .unwrap();
+        assert_eq!("mewtwo", pokemon.name());
+        assert_eq!(150, json!(pokemon)["id"]);
+    }
+
+    #[tokio::test]
+    async fn pokeapi_builds_the_evolution_chain_of_the_pokemon() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                json!({ "variables": { "name": "vaporeon" } }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!(
+                {
+                    "data":{
+                        "species":[
+                            {
+                                "chain":{
+                                    "members":[
+                                        {
+                                            "id": 133,
+                                            "name": "eevee",
+                                            "evolves_from_species_id": null,
+                                            "evolutions": []
+                                        },
+                                        {
+                                            "id": 134,
+                                            "name": "vaporeon",
+                                            "evolves_from_species_id": 133,
+                                            "evolutions": [
+                                                {
+                                                    "min_level": null,
+                                                    "min_happiness": null,
+                                                    "time_of_day": "",
+                                                    "trigger": { "name": "use-item" },
+                                                    "item": { "name": "water-stone" },
+                                                    "held_item": null,
+                                                    "known_move": null
+                                                }
+                                            ]
+                                        }
+                                    ]
+                                }
+                            }
+                        ]
+                    }
+                }
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+
+        assert_eq!(
+            json!({
+                "name": "eevee",
+                "evolvesTo": [{
+                    "name": "vaporeon",
+                    "conditions": [{ "trigger": "use-item", "item": "water-stone" }],
+                    "evolvesTo": []
+                }]
+            }),
+            json!(poke_api
+                .get_evolutions("vaporeon".to_string())
+                .await
+                .unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn pokeapi_handles_correctly_evolutions_of_non_existent_pokemon() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "data": { "species": [] } })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+        assert!(matches!(
+            poke_api.get_evolutions("any_pokemon".into()).await,
+            Err(PokemonError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn pokeapi_retrieves_the_species_names() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                json!({ "operationName": "GqlSpeciesNames" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "species": [{ "name": "bulbasaur" }, { "name": "ivysaur" }] }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+        assert_eq!(
+            vec!["bulbasaur".to_string(), "ivysaur".to_string()],
+            poke_api.get_names().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn pokeapi_lists_a_page_of_the_species_matching_the_filter() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "operationName": "GqlSpeciesPage",
+                "variables": {
+                    "where": { "_and": [
+                        { "pokemon_v2_pokemonhabitat": { "name": { "_eq": "cave" } } },
+                        { "is_legendary": { "_eq": false } },
+                        { "name": { "_ilike": "%bat%" } },
+                        { "id": { "_gt": 41 } }
+                    ] },
+                    "total_where": { "_and": [
+                        { "pokemon_v2_pokemonhabitat": { "name": { "_eq": "cave" } } },
+                        { "is_legendary": { "_eq": false } },
+                        { "name": { "_ilike": "%bat%" } }
+                    ] },
+                    "limit": 2,
+                    "offset": 0
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "species": [{ "id": 42, "name": "golbat" }, { "id": 169, "name": "crobat" }],
+                    "total": { "aggregate": { "count": 3 } }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+        let (species, total) = poke_api
+            .list(
+                SpeciesFilter::new(
+                    Some("cave".to_string()),
+                    Some(false),
+                    None,
+                    None,
+                    Some("bat".to_string()),
+                ),
+                PageRequest::new(2, 0, Some(41)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(3, total);
+        assert_eq!(
+            vec![
+                SpeciesSummary::new(42, "golbat".to_string()),
+                SpeciesSummary::new(169, "crobat".to_string())
+            ],
+            species
+        );
+    }
+
+    #[tokio::test]
+    async fn pokeapi_retries_transient_errors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(build_pokeapi_response(
+                    "mewtwo",
+                    None,
+                    &[("any_description", "en")],
+                    true,
+                )),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+        assert!(poke_api.get(by_name("mewtwo"), english()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn pokeapi_reports_unavailability_when_retries_are_exhausted() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let poke_api = PokeApi::new(server.uri().parse().unwrap(), 10, retry_policy()).unwrap();
+        assert!(matches!(
+            poke_api.get(by_name("any_pokemon"), english()).await,
+            Err(PokemonError::UpstreamUnavailable(_))
+        ));
+    }
+
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy::new(
+            3,
+            Duration::from_millis(1),
+            Duration::from_millis(10),
+            0.5,
+            vec![502, 503, 504],
+            RetryBudget::new(1.0, 10),
+        )
+    }
+
+    fn by_name(pokemon_name: &str) -> PokemonLookup {
+        PokemonLookup::ByName(pokemon_name.to_string())
+    }
+
+    fn english() -> Vec<Language> {
+        vec![Language::new("en")]
     }
 
     fn build_pokeapi_response(
         pokemon_name: &str,
         habitat: Option<&str>,
-        descriptions: &[&str],
+        descriptions: &[(&str, &str)],
         is_legendary: bool,
     ) -> Value {
         let flavor_texts = descriptions
             .iter()
-            .map(|d| json!({ "flavor_text": d }))
+            .map(|(d, l)| json!({ "flavor_text": d, "language": { "name": l } }))
             .collect::<Vec<Value>>();
 
         match habitat {
@@ -250,12 +819,15 @@ mod tests {
                        "data":{
                           "info":[
                              {
+                                "id":150,
                                 "name":pokemon_name,
                                 "habitat":{
                                    "name":habitat_name
                                 },
                                  "descriptions": flavor_texts,
-                                "is_legendary":is_legendary
+                                "is_legendary":is_legendary,
+                                "is_mythical":false,
+                                "varieties":[]
                              }
                           ]
                        }
@@ -268,9 +840,12 @@ mod tests {
                        "data":{
                           "info":[
                              {
+                                "id":150,
                                 "name":pokemon_name,
                                  "descriptions": flavor_texts,
-                                "is_legendary":is_legendary
+                                "is_legendary":is_legendary,
+                                "is_mythical":false,
+                                "varieties":[]
                              }
                           ]
                        }
@@ -1,8 +1,13 @@
 use std::convert::TryFrom;
 
-use graphql_client::Response;
+use graphql_client::{GraphQLQuery, QueryBody, Response};
+use serde::Serialize;
+use serde_json::{json, Value};
 
-use crate::pokemon_bounded_context::domain::Pokemon;
+use crate::pokemon_bounded_context::domain::{
+    Ability, EvolutionChain, EvolutionCondition, EvolutionSpecies, Language, PageRequest, Pokemon,
+    PokemonDetails, PokemonError, SpeciesFilter, SpeciesSummary, Stat,
+};
 
 #[derive(graphql_client::GraphQLQuery)]
 #[graphql(
@@ -15,27 +20,313 @@ pub type GqlPokemonVariables = gql_pokemon::Variables;
 
 pub type GqlPokemonResponse = gql_pokemon::ResponseData;
 
-impl TryFrom<Response<GqlPokemonResponse>> for Pokemon {
-    type Error = anyhow::Error;
-    fn try_from(graphql_response: Response<GqlPokemonResponse>) -> Result<Self, Self::Error> {
+#[derive(graphql_client::GraphQLQuery)]
+#[graphql(
+    schema_path = "src/pokemon_bounded_context/adapter/out/poke_api/graphql_api/schema.graphql",
+    query_path = "src/pokemon_bounded_context/adapter/out/poke_api/graphql_api/gql_evolution_chain.graphql"
+)]
+pub struct GqlEvolutionChain;
+
+pub type GqlEvolutionChainVariables = gql_evolution_chain::Variables;
+
+pub type GqlEvolutionChainResponse = gql_evolution_chain::ResponseData;
+
+#[derive(graphql_client::GraphQLQuery)]
+#[graphql(
+    schema_path = "src/pokemon_bounded_context/adapter/out/poke_api/graphql_api/schema.graphql",
+    query_path = "src/pokemon_bounded_context/adapter/out/poke_api/graphql_api/gql_species_names.graphql"
+)]
+pub struct GqlSpeciesNames;
+
+pub type GqlSpeciesNamesVariables = gql_species_names::Variables;
+
+pub type GqlSpeciesNamesResponse = gql_species_names::ResponseData;
+
+// only the query and its response types are used: see `GqlSpeciesPageQuery`
+#[allow(dead_code)]
+#[derive(graphql_client::GraphQLQuery)]
+#[graphql(
+    schema_path = "src/pokemon_bounded_context/adapter/out/poke_api/graphql_api/schema.graphql",
+    query_path = "src/pokemon_bounded_context/adapter/out/poke_api/graphql_api/gql_species_page.graphql"
+)]
+struct GqlSpeciesPage;
+
+/// The species page query with the filters written in json:
+/// the generated input types would send every missing condition as `null`.
+pub struct GqlSpeciesPageQuery;
+
+#[derive(Serialize)]
+pub struct GqlSpeciesPageVariables {
+    #[serde(rename = "where")]
+    where_: Value,
+    limit: i64,
+    offset: i64,
+    total_where: Value,
+}
+
+pub type GqlSpeciesPageResponse = gql_species_page::ResponseData;
+
+impl GraphQLQuery for GqlSpeciesPageQuery {
+    type Variables = GqlSpeciesPageVariables;
+    type ResponseData = GqlSpeciesPageResponse;
+
+    fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+        QueryBody {
+            variables,
+            query: gql_species_page::QUERY,
+            operation_name: gql_species_page::OPERATION_NAME,
+        }
+    }
+}
+
+/// The graphql response together with the languages requested for the description,
+/// sorted by preference.
+impl TryFrom<(Response<GqlPokemonResponse>, &[Language])> for Pokemon {
+    type Error = PokemonError;
+    fn try_from(
+        (graphql_response, languages): (Response<GqlPokemonResponse>, &[Language]),
+    ) -> Result<Self, Self::Error> {
+        into_pokemons(graphql_response, languages)?
+            .into_iter()
+            .next()
+            .ok_or(PokemonError::NotFound)
+    }
+}
+
+impl TryFrom<Response<GqlEvolutionChainResponse>> for EvolutionChain {
+    type Error = PokemonError;
+    fn try_from(
+        graphql_response: Response<GqlEvolutionChainResponse>,
+    ) -> Result<Self, Self::Error> {
         let gql_errors = graphql_response.errors;
 
-        let response_data = graphql_response
-            .data
-            .ok_or_else(|| anyhow::anyhow!("Empty response with errors: {:?}", gql_errors))?;
-
-        let gql_pokemon_info = response_data
-            .info
-            .first()
-            .ok_or_else(|| anyhow::anyhow!("Pokemon not found".to_string()))?;
-        Ok(Pokemon::new(
-            gql_pokemon_info
-                .descriptions
-                .first()
-                .map(|d| d.flavor_text.clone()),
-            gql_pokemon_info.habitat.as_ref().map(|h| h.name.clone()),
-            gql_pokemon_info.is_legendary,
-            gql_pokemon_info.name.clone(),
+        let response_data = graphql_response.data.ok_or_else(|| {
+            PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                "Empty response with errors: {:?}",
+                gql_errors
+            ))
+        })?;
+
+        let gql_chain = response_data
+            .species
+            .into_iter()
+            .next()
+            .ok_or(PokemonError::NotFound)?
+            .chain
+            .ok_or_else(|| {
+                PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                    "Species without evolution chain"
+                ))
+            })?;
+
+        EvolutionChain::from_species(
+            gql_chain
+                .members
+                .into_iter()
+                .map(|member| {
+                    EvolutionSpecies::new(
+                        member.id,
+                        member.name,
+                        member.evolves_from_species_id,
+                        member
+                            .evolutions
+                            .into_iter()
+                            .filter_map(|evolution| {
+                                Some(
+                                    EvolutionCondition::new(evolution.trigger?.name)
+                                        .with_min_level(evolution.min_level)
+                                        .with_min_happiness(evolution.min_happiness)
+                                        .with_item(evolution.item.map(|i| i.name))
+                                        .with_held_item(evolution.held_item.map(|i| i.name))
+                                        .with_known_move(evolution.known_move.map(|m| m.name))
+                                        .with_time_of_day(evolution.time_of_day),
+                                )
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+pub fn into_species_names(
+    graphql_response: Response<GqlSpeciesNamesResponse>,
+) -> Result<Vec<String>, PokemonError> {
+    let gql_errors = graphql_response.errors;
+
+    let response_data = graphql_response.data.ok_or_else(|| {
+        PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+            "Empty response with errors: {:?}",
+            gql_errors
         ))
+    })?;
+
+    Ok(response_data
+        .species
+        .into_iter()
+        .map(|species| species.name)
+        .collect())
+}
+
+/// The variables of the page of the species matching `filter`.
+///
+/// The total is counted over the species matching `filter` only,
+/// regardless of where the page starts.
+pub fn species_page_variables(
+    filter: &SpeciesFilter,
+    page: &PageRequest,
+) -> GqlSpeciesPageVariables {
+    let mut conditions = Vec::new();
+    if let Some(habitat) = filter.habitat() {
+        conditions.push(json!({ "pokemon_v2_pokemonhabitat": { "name": { "_eq": habitat } } }));
+    }
+    if let Some(is_legendary) = filter.is_legendary() {
+        conditions.push(json!({ "is_legendary": { "_eq": is_legendary } }));
+    }
+    if let Some(pokemon_type) = filter.pokemon_type() {
+        conditions.push(
+            json!({ "pokemon_v2_pokemons": { "pokemon_v2_pokemontypes": {
+            "pokemon_v2_type": { "name": { "_eq": pokemon_type } }
+        } } }),
+        );
+    }
+    if let Some(generation) = filter.generation() {
+        conditions.push(json!({ "pokemon_v2_generation": { "name": { "_eq": generation } } }));
+    }
+    if let Some(name_contains) = filter.name_contains() {
+        conditions.push(json!({ "name": {
+            "_ilike": format!("%{}%", escape_like_pattern(name_contains))
+        } }));
+    }
+    let total_where = json!({ "_and": conditions });
+
+    if let Some(after_id) = page.after_id() {
+        conditions.push(json!({ "id": { "_gt": after_id } }));
+    }
+    GqlSpeciesPageVariables {
+        where_: json!({ "_and": conditions }),
+        limit: page.limit().into(),
+        offset: page.offset().into(),
+        total_where,
     }
 }
+
+/// Matches `value` literally inside a LIKE pattern.
+fn escape_like_pattern(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// The species of the page together with the number of species matching the filter.
+pub fn into_species_page(
+    graphql_response: Response<GqlSpeciesPageResponse>,
+) -> Result<(Vec<SpeciesSummary>, u64), PokemonError> {
+    let gql_errors = graphql_response.errors;
+
+    let response_data = graphql_response.data.ok_or_else(|| {
+        PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+            "Empty response with errors: {:?}",
+            gql_errors
+        ))
+    })?;
+
+    let total = response_data
+        .total
+        .aggregate
+        .map_or(0, |aggregate| aggregate.count as u64);
+    Ok((
+        response_data
+            .species
+            .into_iter()
+            .map(|species| SpeciesSummary::new(species.id as u32, species.name))
+            .collect(),
+        total,
+    ))
+}
+
+/// Convert every pokemon found in the graphql response,
+/// with the description in the first available language of `languages`.
+pub fn into_pokemons(
+    graphql_response: Response<GqlPokemonResponse>,
+    languages: &[Language],
+) -> Result<Vec<Pokemon>, PokemonError> {
+    let gql_errors = graphql_response.errors;
+
+    let response_data = graphql_response.data.ok_or_else(|| {
+        PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+            "Empty response with errors: {:?}",
+            gql_errors
+        ))
+    })?;
+
+    Ok(response_data
+        .info
+        .iter()
+        .map(|gql_pokemon_info| {
+            let description = languages.iter().find_map(|language| {
+                gql_pokemon_info
+                    .descriptions
+                    .iter()
+                    .find(|d| {
+                        d.language
+                            .as_ref()
+                            .map(|l| Language::new(&l.name))
+                            .as_ref()
+                            .eq(&Some(language))
+                    })
+                    .map(|d| (d.flavor_text.clone(), language.clone()))
+            });
+            let (description, language) = description.unzip();
+
+            Pokemon::new(
+                description,
+                gql_pokemon_info.habitat.as_ref().map(|h| h.name.clone()),
+                gql_pokemon_info.is_legendary,
+                language,
+                gql_pokemon_info.name.clone(),
+            )
+            .with_id(gql_pokemon_info.id as u32)
+            .with_details(details(gql_pokemon_info))
+        })
+        .collect())
+}
+
+/// The species details together with the ones of its default variety, if any.
+fn details(gql_pokemon_info: &gql_pokemon::GqlPokemonInfo) -> PokemonDetails {
+    let variety = gql_pokemon_info.varieties.first();
+    PokemonDetails::new(
+        variety.map_or_else(Vec::new, |v| {
+            v.types
+                .iter()
+                .filter_map(|t| t.pokemon_type.as_ref().map(|t| t.name.clone()))
+                .collect()
+        }),
+        variety.map_or_else(Vec::new, |v| {
+            v.stats
+                .iter()
+                .filter_map(|s| {
+                    s.stat
+                        .as_ref()
+                        .map(|stat| Stat::new(stat.name.clone(), s.base_stat))
+                })
+                .collect()
+        }),
+        variety.map_or_else(Vec::new, |v| {
+            v.abilities
+                .iter()
+                .filter_map(|a| {
+                    a.ability
+                        .as_ref()
+                        .map(|ability| Ability::new(ability.name.clone(), a.is_hidden))
+                })
+                .collect()
+        }),
+        variety.and_then(|v| v.height),
+        variety.and_then(|v| v.weight),
+        gql_pokemon_info.is_mythical,
+        gql_pokemon_info.generation.as_ref().map(|g| g.name.clone()),
+    )
+}
@@ -1,42 +1,165 @@
+use std::convert::TryFrom;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
 use anyhow::Context;
+use redis::aio::ConnectionManager;
 use redis::AsyncCommands;
 
+use crate::pokemon_bounded_context::adapter::out::ExpiringCache;
+use crate::pokemon_bounded_context::domain::{CacheKey, PokemonError};
 use crate::pokemon_bounded_context::port::out::{CacheRetrieval, CacheUpdater};
 
+const INITIAL_RECONNECTION_DELAY: Duration = Duration::from_secs(1);
+const MAX_RECONNECTION_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Clone)]
 pub struct RedisCache {
-    connection_manager: redis::aio::ConnectionManager,
+    connection_manager: Arc<RwLock<Option<ConnectionManager>>>,
+    ttl_seconds: usize,
 }
 
 impl RedisCache {
-    pub async fn new(url: &str) -> anyhow::Result<Self> {
+    /// Connect to Redis.
+    ///
+    /// If Redis is unreachable, the cache starts disabled (lookups miss and updates are dropped)
+    /// and the connection is retried in the background with an exponential backoff.
+    pub async fn new(url: &str, ttl_seconds: usize) -> anyhow::Result<Self> {
         let client = redis::Client::open(url).context("Error creating Redis client")?;
-        let connection_manager = client
-            .get_tokio_connection_manager()
-            .await
-            .context("Error creating the connection manager")?;
+        let connection_manager = Arc::new(RwLock::new(None));
+
+        match client.get_tokio_connection_manager().await {
+            Ok(connection) => set_connection(&connection_manager, connection),
+            Err(e) => {
+                tracing::warn!(
+                    "Redis is unreachable, starting with the cache disabled.\n{:?}",
+                    e
+                );
+                tokio::spawn(reconnect_with_backoff(client, connection_manager.clone()));
+            }
+        }
+
+        Ok(Self {
+            connection_manager,
+            ttl_seconds,
+        })
+    }
+    pub fn is_connected(&self) -> bool {
+        self.connection().is_some()
+    }
+    fn connection(&self) -> Option<ConnectionManager> {
+        self.connection_manager
+            .read()
+            .ok()
+            .and_then(|connection| connection.clone())
+    }
+    #[tracing::instrument(name = "Storing in Redis", skip(self, value), fields(key = %key))]
+    async fn set(
+        &self,
+        key: &CacheKey,
+        value: String,
+        ttl_seconds: usize,
+    ) -> Result<(), PokemonError> {
+        match self.connection() {
+            None => Ok(()),
+            Some(mut connection) => connection
+                .set_ex(key.to_string(), value, ttl_seconds)
+                .await
+                .with_context(|| format!("Error setting value for key: {}", key))
+                .map_err(PokemonError::UpstreamUnavailable),
+        }
+    }
+}
 
-        Ok(Self { connection_manager })
+async fn reconnect_with_backoff(
+    client: redis::Client,
+    connection_manager: Arc<RwLock<Option<ConnectionManager>>>,
+) {
+    let mut delay = INITIAL_RECONNECTION_DELAY;
+    loop {
+        tokio::time::sleep(delay).await;
+        match client.get_tokio_connection_manager().await {
+            Ok(connection) => {
+                tracing::info!("Connected to Redis, the cache is enabled");
+                set_connection(&connection_manager, connection);
+                return;
+            }
+            Err(e) => {
+                delay = std::cmp::min(delay * 2, MAX_RECONNECTION_DELAY);
+                tracing::warn!(
+                    "Failed to connect to Redis, retrying in {:?}.\n{:?}",
+                    delay,
+                    e
+                );
+            }
+        }
+    }
+}
+
+fn set_connection(
+    connection_manager: &RwLock<Option<ConnectionManager>>,
+    connection: ConnectionManager,
+) {
+    if let Ok(mut connection_manager) = connection_manager.write() {
+        *connection_manager = Some(connection);
     }
 }
 
 #[async_trait::async_trait]
 impl CacheRetrieval for RedisCache {
-    async fn get(&self, pokemon_name: &str) -> anyhow::Result<Option<String>> {
-        let mut connection = self.connection_manager.clone();
-        connection
-            .get(pokemon_name)
+    #[tracing::instrument(name = "Getting from Redis", skip(self), fields(key = %key))]
+    async fn get(&self, key: &CacheKey) -> Result<Option<String>, PokemonError> {
+        match self.connection() {
+            None => Ok(None),
+            Some(mut connection) => connection
+                .get(key.to_string())
+                .await
+                .with_context(|| format!("Error retrieving key: {}", key))
+                .map_err(PokemonError::UpstreamUnavailable),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExpiringCache for RedisCache {
+    #[tracing::instrument(name = "Getting from Redis", skip(self), fields(key = %key))]
+    async fn get_with_ttl(
+        &self,
+        key: &CacheKey,
+    ) -> Result<Option<(String, Option<Duration>)>, PokemonError> {
+        let mut connection = match self.connection() {
+            None => return Ok(None),
+            Some(connection) => connection,
+        };
+        let (value, ttl_millis): (Option<String>, i64) = redis::pipe()
+            .get(key.to_string())
+            .pttl(key.to_string())
+            .query_async(&mut connection)
             .await
-            .with_context(|| format!("Error retrieving pokemon: {}", pokemon_name))
+            .with_context(|| format!("Error retrieving key: {}", key))
+            .map_err(PokemonError::UpstreamUnavailable)?;
+        // PTTL is negative for the keys without expiration
+        let ttl = u64::try_from(ttl_millis).ok().map(Duration::from_millis);
+        Ok(value.map(|value| (value, ttl)))
     }
 }
 
 #[async_trait::async_trait]
 impl CacheUpdater for RedisCache {
-    async fn update(&self, pokemon_name: &str, pokemon_description: String) -> anyhow::Result<()> {
-        let mut connection = self.connection_manager.clone();
-        connection
-            .set(pokemon_name, pokemon_description)
-            .await
-            .with_context(|| format!("Error setting description for pokemon: {}", pokemon_name))
+    async fn update(
+        &self,
+        key: &CacheKey,
+        pokemon_description: String,
+    ) -> Result<(), PokemonError> {
+        self.set(key, pokemon_description, self.ttl_seconds).await
+    }
+    async fn update_with_ttl(
+        &self,
+        key: &CacheKey,
+        value: String,
+        ttl: Duration,
+    ) -> Result<(), PokemonError> {
+        // Redis rejects a zero expiration
+        self.set(key, value, (ttl.as_secs() as usize).max(1)).await
     }
 }
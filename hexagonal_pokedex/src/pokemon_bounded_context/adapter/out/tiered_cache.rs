@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use crate::pokemon_bounded_context::domain::{CacheKey, PokemonError};
+use crate::pokemon_bounded_context::port::out::{CacheRetrieval, CacheUpdater};
+
+/// Two-level cache: the `l1` cache is looked up first
+/// and it is filled with the values found in the `l2` cache,
+/// kept only for the time they have left to live in the `l2` cache.
+#[derive(Clone)]
+pub struct TieredCache<L1, L2> {
+    l1: L1,
+    l2: L2,
+}
+
+/// A cache reporting how long its entries have left to live.
+#[async_trait::async_trait]
+pub trait ExpiringCache {
+    /// The value with its remaining time to live, `None` when it never expires.
+    async fn get_with_ttl(
+        &self,
+        key: &CacheKey,
+    ) -> Result<Option<(String, Option<Duration>)>, PokemonError>;
+}
+
+impl<L1, L2> TieredCache<L1, L2> {
+    pub fn new(l1: L1, l2: L2) -> Self {
+        Self { l1, l2 }
+    }
+    pub fn l2(&self) -> &L2 {
+        &self.l2
+    }
+}
+
+#[async_trait::async_trait]
+impl<L1, L2> CacheRetrieval for TieredCache<L1, L2>
+where
+    L1: CacheRetrieval + CacheUpdater + Send + Sync,
+    L2: ExpiringCache + Send + Sync,
+{
+    /// A failure to fill the `l1` cache is only logged, since the value was found.
+    async fn get(&self, key: &CacheKey) -> Result<Option<String>, PokemonError> {
+        if let Ok(Some(value)) = self.l1.get(key).await {
+            return Ok(Some(value));
+        }
+        let (value, ttl) = match self.l2.get_with_ttl(key).await? {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+        let filled = match ttl {
+            Some(ttl) => self.l1.update_with_ttl(key, value.clone(), ttl).await,
+            None => self.l1.update(key, value.clone()).await,
+        };
+        if let Err(e) = filled {
+            tracing::warn!("Failed to fill the in-memory cache.\n{:?}", e);
+        }
+        Ok(Some(value))
+    }
+}
+
+#[async_trait::async_trait]
+impl<L1, L2> CacheUpdater for TieredCache<L1, L2>
+where
+    L1: CacheUpdater + Send + Sync,
+    L2: CacheUpdater + Send + Sync,
+{
+    async fn update(
+        &self,
+        key: &CacheKey,
+        pokemon_description: String,
+    ) -> Result<(), PokemonError> {
+        self.l1.update(key, pokemon_description.clone()).await?;
+        self.l2.update(key, pokemon_description).await
+    }
+    async fn update_with_ttl(
+        &self,
+        key: &CacheKey,
+        value: String,
+        ttl: Duration,
+    ) -> Result<(), PokemonError> {
+        self.l1.update_with_ttl(key, value.clone(), ttl).await?;
+        self.l2.update_with_ttl(key, value, ttl).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pokemon_bounded_context::adapter::out::MemoryCache;
+    use crate::pokemon_bounded_context::domain::TranslationStyle;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn tiered_cache_fills_l1_with_l2_values() {
+        let l1 = MemoryCache::new(10, 60);
+        let l2 = MemoryCache::new(10, 60);
+        l2.update(&key(), "description".to_string()).await.unwrap();
+
+        let cache = TieredCache::new(l1.clone(), l2);
+        assert_eq!(
+            Some("description".to_string()),
+            cache.get(&key()).await.unwrap()
+        );
+        assert_eq!(
+            Some("description".to_string()),
+            l1.get(&key()).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn tiered_cache_fills_l1_only_for_the_ttl_left_in_l2() {
+        let l1 = MemoryCache::new(10, 60);
+        let l2 = MemoryCache::new(10, 60);
+        l2.update_with_ttl(&key(), "not found".to_string(), Duration::from_millis(50))
+            .await
+            .unwrap();
+
+        let cache = TieredCache::new(l1.clone(), l2);
+        assert!(cache.get(&key()).await.unwrap().is_some());
+        assert!(l1.get(&key()).await.unwrap().is_some());
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(None, l1.get(&key()).await.unwrap());
+        assert_eq!(None, cache.get(&key()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn tiered_cache_updates_both_levels() {
+        let l1 = MemoryCache::new(10, 60);
+        let l2 = MemoryCache::new(10, 60);
+
+        let cache = TieredCache::new(l1.clone(), l2.clone());
+        cache
+            .update(&key(), "description".to_string())
+            .await
+            .unwrap();
+        assert!(l1.get(&key()).await.unwrap().is_some());
+        assert!(l2.get(&key()).await.unwrap().is_some());
+    }
+
+    fn key() -> CacheKey {
+        CacheKey::translation("mewtwo".to_string(), TranslationStyle::yoda(), None, 1)
+    }
+}
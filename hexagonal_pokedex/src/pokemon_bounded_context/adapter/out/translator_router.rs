@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use crate::pokemon_bounded_context::domain::{
+    PokemonError, Translated, TranslationSource, TranslationStyle,
+};
+use crate::pokemon_bounded_context::port::out::Translator;
+
+/// Which translator serves a style.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TranslatorMode {
+    Remote,
+    Local,
+    /// The remote translator, falling back to the local one when it fails.
+    RemoteWithLocalFallback,
+}
+
+/// Route every style to the `remote` or the `local` translator, according to its mode.
+///
+/// Styles without a mode are served by the remote translator.
+pub struct TranslatorRouter<R, L> {
+    remote: R,
+    local: L,
+    modes: HashMap<TranslationStyle, TranslatorMode>,
+}
+
+impl<R, L> TranslatorRouter<R, L> {
+    pub fn new(remote: R, local: L, modes: HashMap<TranslationStyle, TranslatorMode>) -> Self {
+        Self {
+            remote,
+            local,
+            modes,
+        }
+    }
+    fn mode(&self, style: &TranslationStyle) -> TranslatorMode {
+        self.modes
+            .get(style)
+            .copied()
+            .unwrap_or(TranslatorMode::Remote)
+    }
+}
+
+#[async_trait::async_trait]
+impl<R, L> Translator for TranslatorRouter<R, L>
+where
+    R: Translator + Send + Sync,
+    L: Translator + Send + Sync,
+{
+    fn supports(&self, style: &TranslationStyle) -> bool {
+        match self.mode(style) {
+            TranslatorMode::Remote => self.remote.supports(style),
+            TranslatorMode::Local => self.local.supports(style),
+            TranslatorMode::RemoteWithLocalFallback => {
+                self.remote.supports(style) || self.local.supports(style)
+            }
+        }
+    }
+    fn source(&self, style: &TranslationStyle) -> TranslationSource {
+        match self.mode(style) {
+            TranslatorMode::Local => self.local.source(style),
+            TranslatorMode::Remote | TranslatorMode::RemoteWithLocalFallback => {
+                self.remote.source(style)
+            }
+        }
+    }
+    /// A local translation standing in for a failed remote one is reported as a fallback.
+    ///
+    /// The span records the translator that served the style: remote or local.
+    #[tracing::instrument(
+        name = "Routing the translation",
+        skip(self, text),
+        fields(style = %style, translator)
+    )]
+    async fn translate(
+        &self,
+        style: &TranslationStyle,
+        text: &str,
+    ) -> Result<Translated, PokemonError> {
+        let span = tracing::Span::current();
+        match self.mode(style) {
+            TranslatorMode::Remote => {
+                span.record("translator", &"remote");
+                self.remote.translate(style, text).await
+            }
+            TranslatorMode::Local => {
+                span.record("translator", &"local");
+                self.local.translate(style, text).await
+            }
+            TranslatorMode::RemoteWithLocalFallback => {
+                span.record("translator", &"remote");
+                match self.remote.translate(style, text).await {
+                    Ok(translation) => Ok(translation),
+                    Err(e) if self.local.supports(style) => {
+                        span.record("translator", &"local");
+                        tracing::warn!(
+                            "Remote translation failed, falling back to the local translator.\n{:?}",
+                            e
+                        );
+                        let translation = self.local.translate(style, text).await?;
+                        Ok(Translated::new(
+                            translation.into_text(),
+                            TranslationSource::LocalFallback,
+                        ))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use crate::pokemon_bounded_context::port::out::MockTranslator;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn translator_router_serves_styles_with_their_mode() {
+        let mut remote = MockTranslator::new();
+        let mut local = MockTranslator::new();
+        remote
+            .expect_translate()
+            .with(eq(TranslationStyle::new("pirate")), eq("text"))
+            .times(1)
+            .returning(|_, _| {
+                Ok(Translated::new(
+                    "remote".to_string(),
+                    TranslationSource::Remote,
+                ))
+            });
+        local
+            .expect_translate()
+            .with(eq(TranslationStyle::yoda()), eq("text"))
+            .times(1)
+            .returning(|_, _| {
+                Ok(Translated::new(
+                    "local".to_string(),
+                    TranslationSource::Local,
+                ))
+            });
+
+        let router = TranslatorRouter::new(remote, local, modes(TranslatorMode::Local));
+        assert_eq!(
+            Translated::new("remote".to_string(), TranslationSource::Remote),
+            router
+                .translate(&TranslationStyle::new("pirate"), "text")
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Translated::new("local".to_string(), TranslationSource::Local),
+            router
+                .translate(&TranslationStyle::yoda(), "text")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn translator_router_falls_back_to_the_local_translator() {
+        let mut remote = MockTranslator::new();
+        let mut local = MockTranslator::new();
+        remote
+            .expect_translate()
+            .times(1)
+            .returning(|_, _| Err(PokemonError::RateLimited));
+        local.expect_supports().return_const(true);
+        local.expect_translate().times(1).returning(|_, _| {
+            Ok(Translated::new(
+                "local".to_string(),
+                TranslationSource::Local,
+            ))
+        });
+
+        let router = TranslatorRouter::new(
+            remote,
+            local,
+            modes(TranslatorMode::RemoteWithLocalFallback),
+        );
+        assert_eq!(
+            Translated::new("local".to_string(), TranslationSource::LocalFallback),
+            router
+                .translate(&TranslationStyle::yoda(), "text")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn translator_router_reports_remote_errors_without_local_fallback() {
+        let mut remote = MockTranslator::new();
+        let mut local = MockTranslator::new();
+        remote
+            .expect_translate()
+            .times(1)
+            .returning(|_, _| Err(PokemonError::RateLimited));
+        local.expect_translate().times(0);
+
+        let router = TranslatorRouter::new(remote, local, modes(TranslatorMode::Remote));
+        assert!(matches!(
+            router.translate(&TranslationStyle::yoda(), "text").await,
+            Err(PokemonError::RateLimited)
+        ));
+    }
+
+    fn modes(yoda_mode: TranslatorMode) -> HashMap<TranslationStyle, TranslatorMode> {
+        vec![(TranslationStyle::yoda(), yoda_mode)]
+            .into_iter()
+            .collect()
+    }
+}
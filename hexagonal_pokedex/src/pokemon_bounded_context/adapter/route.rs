@@ -1,6 +1,127 @@
-pub use pokemon::pokemon;
+use actix_web::http::header::{
+    CacheControl, CacheDirective, ContentType, ETag, EntityTag, Header, IfNoneMatch,
+    CONTENT_LANGUAGE, VARY,
+};
+use actix_web::http::StatusCode;
+use actix_web::{HttpRequest, HttpResponse};
+
+pub use error::invalid_request;
+pub use metrics::metrics;
+pub use pokemon::{pokemon, pokemon_by_id};
+pub use pokemon_batch::{pokemon_batch, pokemon_batch_post};
+pub use pokemon_evolutions::pokemon_evolutions;
+pub use pokemon_list::pokemon_list;
 pub use pokemon_translated::pokemon_translated;
+pub use pokemon_translation_rule::pokemon_translation_rule;
+pub use readiness::readiness;
+
+use crate::pokemon_bounded_context::adapter::out::{
+    CacheBackend, CachingPokemonRetrieval, CircuitBreaker, FuntranslationApi, LocalTranslator,
+    MeteredCache, PokeApi, TranslatorRouter,
+};
+use crate::pokemon_bounded_context::adapter::route::fields::{select_fields, FieldsQuery};
+use crate::pokemon_bounded_context::domain::Pokemon;
+use crate::pokemon_bounded_context::port::service::{
+    PokemonInfo, PokemonTranslator, SpeciesCatalog,
+};
 
 mod error;
+mod fields;
+mod language;
+mod metrics;
 mod pokemon;
+mod pokemon_batch;
+mod pokemon_evolutions;
+mod pokemon_list;
 mod pokemon_translated;
+mod pokemon_translation_rule;
+mod readiness;
+
+/// The pokemon service as wired at startup.
+type Info = PokemonInfo<
+    CachingPokemonRetrieval<CircuitBreaker<PokeApi>, CacheBackend>,
+    CircuitBreaker<PokeApi>,
+>;
+
+/// The species listing service as wired at startup.
+type Catalog = SpeciesCatalog<CircuitBreaker<PokeApi>>;
+
+/// The translator service as wired at startup.
+type Translator = PokemonTranslator<
+    TranslatorRouter<CircuitBreaker<FuntranslationApi>, LocalTranslator>,
+    MeteredCache<CacheBackend>,
+    MeteredCache<CacheBackend>,
+>;
+
+/// Freshness of the pokemon responses of each route, advertised in their `Cache-Control` header.
+#[derive(Clone, Copy)]
+pub struct ResponseCaching {
+    pokemon_max_age_seconds: u32,
+    translated_max_age_seconds: u32,
+}
+
+impl ResponseCaching {
+    pub fn new(pokemon_max_age_seconds: u32, translated_max_age_seconds: u32) -> Self {
+        Self {
+            pokemon_max_age_seconds,
+            translated_max_age_seconds,
+        }
+    }
+    fn pokemon(&self) -> CacheControl {
+        public_max_age(self.pokemon_max_age_seconds)
+    }
+    /// An untranslated description may be translated by the next request, so it is not stored.
+    fn translated(&self, pokemon: &Pokemon) -> CacheControl {
+        match pokemon.translation() {
+            Some(translation) if !translation.is_applied() => {
+                CacheControl(vec![CacheDirective::NoStore])
+            }
+            _ => public_max_age(self.translated_max_age_seconds),
+        }
+    }
+}
+
+fn public_max_age(max_age_seconds: u32) -> CacheControl {
+    CacheControl(vec![
+        CacheDirective::Public,
+        CacheDirective::MaxAge(max_age_seconds),
+    ])
+}
+
+/// Build the json response for the pokemon with the requested fields,
+/// reporting the language of the description in the `Content-Language` header.
+///
+/// The strong `ETag` identifies the returned data, so it changes with the requested fields:
+/// a request whose `If-None-Match` matches it gets a `304 Not Modified` without body.
+fn pokemon_response(
+    request: &HttpRequest,
+    pokemon: &Pokemon,
+    fields_query: &FieldsQuery,
+    cache_control: CacheControl,
+) -> HttpResponse {
+    let body = select_fields(pokemon, fields_query).to_string();
+    let etag = EntityTag::strong(format!("{}", sha1::Sha1::from(&body).digest()));
+
+    let mut response = HttpResponse::Ok();
+    if let Some(language) = pokemon.language() {
+        response.insert_header((CONTENT_LANGUAGE, language.as_str()));
+    }
+    // the description language is negotiated with the `Accept-Language` header
+    response
+        .insert_header((VARY, "Accept-Language"))
+        .insert_header(ETag(etag.clone()))
+        .insert_header(cache_control);
+    if is_not_modified(request, &etag) {
+        return response.status(StatusCode::NOT_MODIFIED).finish();
+    }
+    response.content_type(ContentType::json()).body(body)
+}
+
+/// `If-None-Match` uses the weak comparison of the entity tags.
+fn is_not_modified(request: &HttpRequest, etag: &EntityTag) -> bool {
+    match IfNoneMatch::parse(request) {
+        Ok(IfNoneMatch::Any) => true,
+        Ok(IfNoneMatch::Items(etags)) => etags.iter().any(|item| item.weak_eq(etag)),
+        Err(_) => false,
+    }
+}
@@ -1,16 +1,108 @@
 use std::error::Error;
 
+use actix_web::http::header::CONTENT_TYPE;
 use actix_web::http::StatusCode;
-use actix_web::ResponseError;
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+use serde::Serialize;
+use tracing_actix_web::RequestId;
+
+use crate::pokemon_bounded_context::domain::PokemonError;
+
+const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
 
 #[derive(thiserror::Error)]
 pub enum PokedexError {
-    #[error("Unable to process request: {0}")]
-    InvalidRequest(#[source] anyhow::Error),
+    #[error(transparent)]
+    Pokemon(#[from] PokemonError),
+    /// The path, query or body of the request could not be extracted.
+    #[error("{0}")]
+    InvalidRequest(String),
     #[error("Unexpected internal error: {0}")]
     UnexpectedError(#[from] anyhow::Error),
 }
 
+impl PokedexError {
+    /// Stable identifier of the problem, used as last segment of its type URI.
+    fn code(&self) -> &'static str {
+        match self {
+            PokedexError::Pokemon(PokemonError::NotFound)
+            | PokedexError::Pokemon(PokemonError::NotFoundSimilarTo(_)) => "not-found",
+            PokedexError::Pokemon(PokemonError::InvalidName(_)) => "invalid-name",
+            PokedexError::Pokemon(PokemonError::InvalidId(_)) => "invalid-id",
+            PokedexError::Pokemon(PokemonError::UnknownStyle(_)) => "unknown-style",
+            PokedexError::Pokemon(PokemonError::BatchTooLarge(_)) => "batch-too-large",
+            PokedexError::Pokemon(PokemonError::InvalidPage(_)) => "invalid-page",
+            PokedexError::Pokemon(PokemonError::InvalidLanguage(_)) => "invalid-language",
+            PokedexError::Pokemon(PokemonError::RateLimited) => "rate-limited",
+            PokedexError::Pokemon(PokemonError::UpstreamTimeout(_)) => "upstream-timeout",
+            PokedexError::Pokemon(PokemonError::UpstreamUnavailable(_)) => "upstream-unavailable",
+            PokedexError::InvalidRequest(_) => "invalid-request",
+            PokedexError::UnexpectedError(_) => "internal-error",
+        }
+    }
+    fn title(&self) -> &'static str {
+        match self {
+            PokedexError::Pokemon(PokemonError::NotFound)
+            | PokedexError::Pokemon(PokemonError::NotFoundSimilarTo(_)) => "Pokemon not found",
+            PokedexError::Pokemon(PokemonError::InvalidName(_)) => "Invalid pokemon name",
+            PokedexError::Pokemon(PokemonError::InvalidId(_)) => "Invalid pokedex number",
+            PokedexError::Pokemon(PokemonError::UnknownStyle(_)) => "Unknown translation style",
+            PokedexError::Pokemon(PokemonError::BatchTooLarge(_)) => "Batch too large",
+            PokedexError::Pokemon(PokemonError::InvalidPage(_)) => "Invalid page",
+            PokedexError::Pokemon(PokemonError::InvalidLanguage(_)) => "Invalid language",
+            PokedexError::Pokemon(PokemonError::RateLimited) => "Too many requests",
+            PokedexError::Pokemon(PokemonError::UpstreamTimeout(_)) => "Upstream timeout",
+            PokedexError::Pokemon(PokemonError::UpstreamUnavailable(_)) => "Upstream unavailable",
+            PokedexError::InvalidRequest(_) => "Invalid request",
+            PokedexError::UnexpectedError(_) => "Internal server error",
+        }
+    }
+    /// Explanation for the clients: the causes are never included.
+    fn detail(&self) -> String {
+        match self {
+            PokedexError::Pokemon(e) => e.to_string(),
+            PokedexError::InvalidRequest(reason) => reason.clone(),
+            PokedexError::UnexpectedError(_) => "Unexpected internal error".to_string(),
+        }
+    }
+    fn status_code(&self) -> StatusCode {
+        match self {
+            PokedexError::Pokemon(PokemonError::NotFound)
+            | PokedexError::Pokemon(PokemonError::NotFoundSimilarTo(_)) => StatusCode::NOT_FOUND,
+            PokedexError::Pokemon(PokemonError::InvalidName(_))
+            | PokedexError::Pokemon(PokemonError::InvalidId(_))
+            | PokedexError::Pokemon(PokemonError::UnknownStyle(_))
+            | PokedexError::Pokemon(PokemonError::BatchTooLarge(_))
+            | PokedexError::Pokemon(PokemonError::InvalidPage(_))
+            | PokedexError::Pokemon(PokemonError::InvalidLanguage(_))
+            | PokedexError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+            PokedexError::Pokemon(PokemonError::RateLimited) => StatusCode::TOO_MANY_REQUESTS,
+            PokedexError::Pokemon(PokemonError::UpstreamTimeout(_)) => StatusCode::GATEWAY_TIMEOUT,
+            PokedexError::Pokemon(PokemonError::UpstreamUnavailable(_)) => StatusCode::BAD_GATEWAY,
+            PokedexError::UnexpectedError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    /// The known pokemon names the client may have meant.
+    fn suggestions(&self) -> Vec<String> {
+        match self {
+            PokedexError::Pokemon(PokemonError::NotFoundSimilarTo(names)) => names.clone(),
+            _ => Vec::new(),
+        }
+    }
+    /// The problem details of the error, without the request specific members.
+    pub fn problem_body(&self) -> ProblemBody {
+        ProblemBody {
+            problem_type: format!("/problems/{}", self.code()),
+            title: self.title(),
+            status: self.status_code().as_u16(),
+            detail: self.detail(),
+            suggestions: self.suggestions(),
+            instance: None,
+            request_id: None,
+        }
+    }
+}
+
 impl std::fmt::Debug for PokedexError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!("{}\n", self))?;
@@ -23,11 +115,98 @@ impl std::fmt::Debug for PokedexError {
     }
 }
 
-impl ResponseError for PokedexError {
+/// A [`PokedexError`] returned as RFC 7807 `application/problem+json`.
+///
+/// The cause chain only reaches the logs, through the `Debug` representation
+/// that `TracingLogger` records on the request span.
+#[derive(thiserror::Error)]
+#[error("{error}")]
+pub struct Problem {
+    #[source]
+    error: PokedexError,
+    instance: String,
+    request_id: Option<RequestId>,
+}
+
+impl Problem {
+    pub fn new(error: impl Into<PokedexError>, request: &HttpRequest) -> Self {
+        Problem {
+            error: error.into(),
+            instance: request.path().to_string(),
+            request_id: request.extensions().get::<RequestId>().copied(),
+        }
+    }
+}
+
+/// The error handler of the path, query and json extractors, reporting their errors as problems.
+pub fn invalid_request(error: impl std::fmt::Display, request: &HttpRequest) -> actix_web::Error {
+    Problem::new(PokedexError::InvalidRequest(error.to_string()), request).into()
+}
+
+impl std::fmt::Debug for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.error, f)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemBody {
+    #[serde(rename = "type")]
+    problem_type: String,
+    title: &'static str,
+    status: u16,
+    detail: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
+}
+
+impl ResponseError for Problem {
     fn status_code(&self) -> StatusCode {
-        match self {
-            PokedexError::InvalidRequest(_) => StatusCode::NOT_FOUND,
-            PokedexError::UnexpectedError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        self.error.status_code()
+    }
+    fn error_response(&self) -> HttpResponse {
+        let body = ProblemBody {
+            instance: Some(self.instance.clone()),
+            request_id: self.request_id.map(|request_id| request_id.to_string()),
+            ..self.error.problem_body()
+        };
+        match serde_json::to_string(&body) {
+            Ok(body) => HttpResponse::build(self.status_code())
+                .insert_header((CONTENT_TYPE, PROBLEM_CONTENT_TYPE))
+                .body(body),
+            Err(_) => HttpResponse::new(self.status_code()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+    use actix_web::{web, FromRequest};
+
+    use super::*;
+
+    #[actix_rt::test]
+    async fn extractor_errors_are_returned_as_problems() {
+        let (request, mut payload) = TestRequest::with_uri("/pokemon/by-id/pikachu")
+            .param("id", "pikachu")
+            .app_data(web::PathConfig::default().error_handler(invalid_request))
+            .to_http_parts();
+
+        let error = web::Path::<u32>::from_request(&request, &mut payload)
+            .await
+            .unwrap_err();
+
+        let response = error.error_response();
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        assert_eq!(
+            PROBLEM_CONTENT_TYPE,
+            response.headers().get(CONTENT_TYPE).unwrap()
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Deserialize)]
+pub struct FieldsQuery {
+    fields: Option<String>,
+}
+
+/// Serialize `value` keeping only the top level members listed in the comma separated
+/// `fields` query parameter; all of them are kept when it is missing.
+///
+/// Unknown field names are ignored.
+pub fn select_fields<T: Serialize>(value: &T, query: &FieldsQuery) -> Value {
+    let value = serde_json::to_value(value).unwrap_or_default();
+    match (query.fields.as_deref(), value) {
+        (Some(fields), Value::Object(members)) => {
+            let fields: Vec<&str> = fields.split(',').map(str::trim).collect();
+            Value::Object(
+                members
+                    .into_iter()
+                    .filter(|(member, _)| fields.contains(&member.as_str()))
+                    .collect(),
+            )
+        }
+        (_, value) => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn select_fields_keeps_only_the_requested_members() {
+        let value = json!({ "name": "mewtwo", "types": ["psychic"], "height": 20 });
+        let query = FieldsQuery {
+            fields: Some("name, types,unknown".to_string()),
+        };
+        assert_eq!(
+            json!({ "name": "mewtwo", "types": ["psychic"] }),
+            select_fields(&value, &query)
+        );
+    }
+
+    #[test]
+    fn select_fields_keeps_all_the_members_without_fields() {
+        let value = json!({ "name": "mewtwo", "height": 20 });
+        assert_eq!(value, select_fields(&value, &FieldsQuery { fields: None }));
+    }
+}
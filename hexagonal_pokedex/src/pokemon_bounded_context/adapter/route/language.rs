@@ -0,0 +1,97 @@
+use actix_web::http::header::ACCEPT_LANGUAGE;
+use actix_web::HttpRequest;
+use serde::Deserialize;
+
+use crate::pokemon_bounded_context::domain::{Language, PokemonError};
+
+#[derive(Deserialize)]
+pub struct LanguageQuery {
+    lang: Option<String>,
+}
+
+/// Negotiate the requested languages, sorted by preference.
+///
+/// The `lang` query parameter takes precedence over the `Accept-Language` header.
+/// A malformed `lang` is rejected, while the malformed tags of the header are ignored.
+pub fn requested_languages(
+    request: &HttpRequest,
+    query: &LanguageQuery,
+) -> Result<Vec<Language>, PokemonError> {
+    match query.lang.as_deref() {
+        Some(lang) => Ok(vec![Language::parse(lang)?]),
+        None => Ok(request
+            .headers()
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|header| header.to_str().ok())
+            .map(parse_accept_language)
+            .unwrap_or_default()),
+    }
+}
+
+fn parse_accept_language(header: &str) -> Vec<Language> {
+    let mut weighted_tags = header
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let tag = parts.next()?.trim();
+            let quality = parts
+                .find_map(|parameter| parameter.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+            if tag.is_empty() || tag == "*" || quality <= 0.0 {
+                None
+            } else {
+                Some((tag, quality))
+            }
+        })
+        .collect::<Vec<(&str, f32)>>();
+    // the sort is stable, so tags with the same quality keep the header order
+    weighted_tags.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    weighted_tags
+        .into_iter()
+        .filter_map(|(tag, _)| Language::parse(tag).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    #[test]
+    fn accept_language_is_sorted_by_quality() {
+        assert_eq!(
+            vec![
+                Language::new("fr-CA"),
+                Language::new("de"),
+                Language::new("en")
+            ],
+            parse_accept_language("en;q=0.5, fr-CA, *;q=0.1, de;q=0.8, it;q=0")
+        );
+    }
+
+    #[test]
+    fn accept_language_ignores_the_malformed_tags() {
+        assert_eq!(
+            vec![Language::new("fr"), Language::new("en")],
+            parse_accept_language("fr, e n;q=0.9, -;q=0.8, en;q=0.5")
+        );
+    }
+
+    #[test]
+    fn lang_query_parameter_overrides_accept_language() {
+        let request = TestRequest::default()
+            .insert_header((ACCEPT_LANGUAGE, "de"))
+            .to_http_request();
+        assert_eq!(
+            vec![Language::new("ja-Hrkt")],
+            requested_languages(
+                &request,
+                &LanguageQuery {
+                    lang: Some("ja-Hrkt".to_string())
+                }
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use actix_web::{web, HttpResponse};
+
+use crate::pokemon_bounded_context::adapter::metrics::Metrics;
+
+/// The metrics of the service in the Prometheus text format.
+pub async fn metrics(metrics: web::Data<Metrics>) -> HttpResponse {
+    match metrics.render() {
+        Ok(rendered) => HttpResponse::Ok()
+            .content_type("text/plain; version=0.0.4")
+            .body(rendered),
+        Err(e) => {
+            tracing::error!("Failed to render the metrics.\n{:?}", e);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
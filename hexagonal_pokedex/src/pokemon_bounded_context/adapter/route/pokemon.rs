@@ -1,20 +1,75 @@
-use actix_web::{web, HttpResponse};
-use anyhow::Context;
+use actix_web::{web, HttpRequest, HttpResponse};
 
-use crate::pokemon_bounded_context::adapter::out::PokeApi;
-use crate::pokemon_bounded_context::adapter::route::error::PokedexError;
-use crate::pokemon_bounded_context::port::service::PokemonInfo;
+use crate::pokemon_bounded_context::adapter::route::error::Problem;
+use crate::pokemon_bounded_context::adapter::route::fields::FieldsQuery;
+use crate::pokemon_bounded_context::adapter::route::language::{
+    requested_languages, LanguageQuery,
+};
+use crate::pokemon_bounded_context::adapter::route::{pokemon_response, Info, ResponseCaching};
+use crate::pokemon_bounded_context::domain::PokemonLookup;
 
+/// The pokemon by name, or by national Pokédex number when the segment is numeric.
 pub async fn pokemon(
-    name: web::Path<String>,
-    pokemon_info: web::Data<PokemonInfo<PokeApi>>,
-) -> Result<HttpResponse, PokedexError> {
+    request: HttpRequest,
+    name_or_id: web::Path<String>,
+    language_query: web::Query<LanguageQuery>,
+    fields_query: web::Query<FieldsQuery>,
+    response_caching: web::Data<ResponseCaching>,
+    pokemon_info: web::Data<Info>,
+) -> Result<HttpResponse, Problem> {
+    let lookup = PokemonLookup::parse(&name_or_id).map_err(|e| Problem::new(e, &request))?;
+    pokemon_for(
+        request,
+        lookup,
+        &language_query,
+        &fields_query,
+        &response_caching,
+        pokemon_info,
+    )
+    .await
+}
+
+/// The pokemon by national Pokédex number only.
+pub async fn pokemon_by_id(
+    request: HttpRequest,
+    id: web::Path<String>,
+    language_query: web::Query<LanguageQuery>,
+    fields_query: web::Query<FieldsQuery>,
+    response_caching: web::Data<ResponseCaching>,
+    pokemon_info: web::Data<Info>,
+) -> Result<HttpResponse, Problem> {
+    let lookup = PokemonLookup::by_id(&id).map_err(|e| Problem::new(e, &request))?;
+    pokemon_for(
+        request,
+        lookup,
+        &language_query,
+        &fields_query,
+        &response_caching,
+        pokemon_info,
+    )
+    .await
+}
+
+async fn pokemon_for(
+    request: HttpRequest,
+    lookup: PokemonLookup,
+    language_query: &LanguageQuery,
+    fields_query: &FieldsQuery,
+    response_caching: &ResponseCaching,
+    pokemon_info: web::Data<Info>,
+) -> Result<HttpResponse, Problem> {
+    let languages =
+        requested_languages(&request, language_query).map_err(|e| Problem::new(e, &request))?;
     let pokemon = pokemon_info
         .into_inner()
-        .get(name.into_inner())
+        .get(lookup, &languages)
         .await
-        .context("Failed to retrieve pokemon")
-        .map_err(PokedexError::InvalidRequest)?;
+        .map_err(|e| Problem::new(e, &request))?;
 
-    Ok(HttpResponse::Ok().json(pokemon))
+    Ok(pokemon_response(
+        &request,
+        &pokemon,
+        fields_query,
+        response_caching.pokemon(),
+    ))
 }
@@ -0,0 +1,128 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::pokemon_bounded_context::adapter::route::error::{PokedexError, Problem, ProblemBody};
+use crate::pokemon_bounded_context::adapter::route::fields::{select_fields, FieldsQuery};
+use crate::pokemon_bounded_context::adapter::route::language::{
+    requested_languages, LanguageQuery,
+};
+use crate::pokemon_bounded_context::adapter::route::{Info, Translator};
+
+/// Query of the batch GET variant: `names` is a comma separated list.
+#[derive(Deserialize)]
+pub struct BatchQuery {
+    names: String,
+    #[serde(default)]
+    translate: bool,
+}
+
+#[derive(Deserialize)]
+pub struct BatchRequest {
+    names: Vec<String>,
+    #[serde(default)]
+    translate: bool,
+}
+
+#[derive(Serialize)]
+struct BatchResponse {
+    results: Vec<BatchItem>,
+}
+
+/// Outcome of a single name of the batch.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum BatchItem {
+    Found { name: String, pokemon: Value },
+    Failed { name: String, error: ProblemBody },
+}
+
+pub async fn pokemon_batch(
+    request: HttpRequest,
+    batch_query: web::Query<BatchQuery>,
+    language_query: web::Query<LanguageQuery>,
+    fields_query: web::Query<FieldsQuery>,
+    pokemon_info: web::Data<Info>,
+    pokemon_translator: web::Data<Translator>,
+) -> Result<HttpResponse, Problem> {
+    let names = batch_query
+        .names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(ToString::to_string)
+        .collect();
+    batch_response(
+        &request,
+        names,
+        batch_query.translate,
+        &language_query,
+        &fields_query,
+        &pokemon_info,
+        &pokemon_translator,
+    )
+    .await
+}
+
+pub async fn pokemon_batch_post(
+    request: HttpRequest,
+    batch_request: web::Json<BatchRequest>,
+    language_query: web::Query<LanguageQuery>,
+    fields_query: web::Query<FieldsQuery>,
+    pokemon_info: web::Data<Info>,
+    pokemon_translator: web::Data<Translator>,
+) -> Result<HttpResponse, Problem> {
+    let batch_request = batch_request.into_inner();
+    batch_response(
+        &request,
+        batch_request.names,
+        batch_request.translate,
+        &language_query,
+        &fields_query,
+        &pokemon_info,
+        &pokemon_translator,
+    )
+    .await
+}
+
+/// Retrieve the pokemon with a single query and, if requested, translate them concurrently.
+async fn batch_response(
+    request: &HttpRequest,
+    names: Vec<String>,
+    translate: bool,
+    language_query: &LanguageQuery,
+    fields_query: &FieldsQuery,
+    pokemon_info: &Info,
+    pokemon_translator: &Translator,
+) -> Result<HttpResponse, Problem> {
+    let languages =
+        requested_languages(request, language_query).map_err(|e| Problem::new(e, request))?;
+    let results = pokemon_info
+        .get_many(names, &languages)
+        .await
+        .map_err(|e| Problem::new(e, request))?;
+
+    let results = join_all(results.into_iter().map(|(name, pokemon)| async move {
+        match pokemon {
+            Ok(pokemon) if translate => BatchItem::Found {
+                name,
+                pokemon: select_fields(
+                    &pokemon_translator.translate(pokemon, None).await,
+                    fields_query,
+                ),
+            },
+            Ok(pokemon) => BatchItem::Found {
+                name,
+                pokemon: select_fields(&pokemon, fields_query),
+            },
+            Err(e) => BatchItem::Failed {
+                name,
+                error: PokedexError::from(e).problem_body(),
+            },
+        }
+    }))
+    .await;
+
+    Ok(HttpResponse::Ok().json(BatchResponse { results }))
+}
@@ -0,0 +1,19 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+
+use crate::pokemon_bounded_context::adapter::out::{CircuitBreaker, PokeApi};
+use crate::pokemon_bounded_context::adapter::route::error::Problem;
+use crate::pokemon_bounded_context::port::service::PokemonEvolutions;
+
+pub async fn pokemon_evolutions(
+    request: HttpRequest,
+    name: web::Path<String>,
+    pokemon_evolutions: web::Data<PokemonEvolutions<CircuitBreaker<PokeApi>>>,
+) -> Result<HttpResponse, Problem> {
+    let evolution_chain = pokemon_evolutions
+        .into_inner()
+        .get(name.into_inner())
+        .await
+        .map_err(|e| Problem::new(e, &request))?;
+
+    Ok(HttpResponse::Ok().json(evolution_chain))
+}
@@ -0,0 +1,95 @@
+use actix_web::http::header::LINK;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+
+use crate::pokemon_bounded_context::adapter::route::error::Problem;
+use crate::pokemon_bounded_context::adapter::route::Catalog;
+use crate::pokemon_bounded_context::domain::SpeciesFilter;
+
+/// Query of the species listing: the pagination parameters followed by the filters.
+#[derive(Deserialize)]
+pub struct ListQuery {
+    limit: Option<u32>,
+    offset: Option<u32>,
+    cursor: Option<String>,
+    habitat: Option<String>,
+    legendary: Option<bool>,
+    #[serde(rename = "type")]
+    pokemon_type: Option<String>,
+    generation: Option<String>,
+    q: Option<String>,
+}
+
+/// A page of the species matching the filters, in Pokédex order.
+///
+/// The `Link` header points to the first page and, unless this is the last one, to the next page.
+pub async fn pokemon_list(
+    request: HttpRequest,
+    list_query: web::Query<ListQuery>,
+    species_catalog: web::Data<Catalog>,
+) -> Result<HttpResponse, Problem> {
+    let list_query = list_query.into_inner();
+    let page = species_catalog
+        .page_request(
+            list_query.limit,
+            list_query.offset,
+            list_query.cursor.as_deref(),
+        )
+        .map_err(|e| Problem::new(e, &request))?;
+    let filter = SpeciesFilter::new(
+        list_query.habitat.clone(),
+        list_query.legendary,
+        list_query.pokemon_type.clone(),
+        list_query.generation.clone(),
+        list_query.q.clone(),
+    );
+
+    let species_page = species_catalog
+        .list(filter, page)
+        .await
+        .map_err(|e| Problem::new(e, &request))?;
+
+    let mut links = vec![format!(
+        "<{}>; rel=\"first\"",
+        page_url(&request, &list_query, page.limit(), None)
+    )];
+    if let Some(next_cursor) = species_page.next_cursor() {
+        links.push(format!(
+            "<{}>; rel=\"next\"",
+            page_url(&request, &list_query, page.limit(), Some(next_cursor))
+        ));
+    }
+
+    Ok(HttpResponse::Ok()
+        .insert_header((LINK, links.join(", ")))
+        .json(species_page))
+}
+
+/// The link to the listing with the same filters and limit, starting after `cursor`.
+///
+/// The link is relative to the host, which is not trusted to be a valid url.
+fn page_url(
+    request: &HttpRequest,
+    list_query: &ListQuery,
+    limit: u32,
+    cursor: Option<&str>,
+) -> String {
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    query.append_pair("limit", &limit.to_string());
+    if let Some(cursor) = cursor {
+        query.append_pair("cursor", cursor);
+    }
+    let filters = [
+        ("habitat", list_query.habitat.clone()),
+        ("legendary", list_query.legendary.map(|l| l.to_string())),
+        ("type", list_query.pokemon_type.clone()),
+        ("generation", list_query.generation.clone()),
+        ("q", list_query.q.clone()),
+    ];
+    for (name, value) in filters.iter() {
+        if let Some(value) = value {
+            query.append_pair(name, value);
+        }
+    }
+    format!("{}?{}", request.path(), query.finish())
+}
@@ -1,27 +1,54 @@
-use actix_web::{web, HttpResponse};
-use anyhow::Context;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
 
-use crate::pokemon_bounded_context::adapter::out::{FuntranslationApi, PokeApi, RedisCache};
-use crate::pokemon_bounded_context::adapter::route::error::PokedexError;
-use crate::pokemon_bounded_context::port::service::{PokemonInfo, PokemonTranslator};
+use crate::pokemon_bounded_context::adapter::route::error::Problem;
+use crate::pokemon_bounded_context::adapter::route::fields::FieldsQuery;
+use crate::pokemon_bounded_context::adapter::route::language::{
+    requested_languages, LanguageQuery,
+};
+use crate::pokemon_bounded_context::adapter::route::{
+    pokemon_response, Info, ResponseCaching, Translator,
+};
+use crate::pokemon_bounded_context::domain::{PokemonLookup, TranslationStyle};
 
+#[derive(Deserialize)]
+pub struct StyleQuery {
+    style: Option<String>,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn pokemon_translated(
+    request: HttpRequest,
     name: web::Path<String>,
-    pokemon_info: web::Data<PokemonInfo<PokeApi>>,
-    pokemon_translator: web::Data<
-        PokemonTranslator<FuntranslationApi, FuntranslationApi, RedisCache, RedisCache>,
-    >,
-) -> Result<HttpResponse, PokedexError> {
+    language_query: web::Query<LanguageQuery>,
+    fields_query: web::Query<FieldsQuery>,
+    response_caching: web::Data<ResponseCaching>,
+    style_query: web::Query<StyleQuery>,
+    pokemon_info: web::Data<Info>,
+    pokemon_translator: web::Data<Translator>,
+) -> Result<HttpResponse, Problem> {
+    let style = style_query.style.as_deref().map(TranslationStyle::new);
+    if let Some(style) = &style {
+        pokemon_translator
+            .validate_style(style)
+            .map_err(|e| Problem::new(e, &request))?;
+    }
+    let lookup = PokemonLookup::parse(&name).map_err(|e| Problem::new(e, &request))?;
+    let languages =
+        requested_languages(&request, &language_query).map_err(|e| Problem::new(e, &request))?;
     let pokemon = pokemon_info
         .into_inner()
-        .get(name.into_inner())
+        .get(lookup, &languages)
         .await
-        .context("Failed to retrieve pokemon")
-        .map_err(PokedexError::InvalidRequest)?;
+        .map_err(|e| Problem::new(e, &request))?;
     let translated_pokemon = pokemon_translator
         .into_inner()
-        .translate(pokemon)
-        .await
-        .context("Failed to translate pokemon description")?;
-    Ok(HttpResponse::Ok().json(&translated_pokemon))
+        .translate(pokemon, style.as_ref())
+        .await;
+    Ok(pokemon_response(
+        &request,
+        &translated_pokemon,
+        &fields_query,
+        response_caching.translated(&translated_pokemon),
+    ))
 }
@@ -0,0 +1,28 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+
+use crate::pokemon_bounded_context::adapter::route::error::Problem;
+use crate::pokemon_bounded_context::adapter::route::language::{
+    requested_languages, LanguageQuery,
+};
+use crate::pokemon_bounded_context::adapter::route::{Info, Translator};
+use crate::pokemon_bounded_context::domain::PokemonLookup;
+
+/// Explain which rule selects the translation style of the pokemon.
+pub async fn pokemon_translation_rule(
+    request: HttpRequest,
+    name: web::Path<String>,
+    language_query: web::Query<LanguageQuery>,
+    pokemon_info: web::Data<Info>,
+    pokemon_translator: web::Data<Translator>,
+) -> Result<HttpResponse, Problem> {
+    let lookup = PokemonLookup::parse(&name).map_err(|e| Problem::new(e, &request))?;
+    let languages =
+        requested_languages(&request, &language_query).map_err(|e| Problem::new(e, &request))?;
+    let pokemon = pokemon_info
+        .into_inner()
+        .get(lookup, &languages)
+        .await
+        .map_err(|e| Problem::new(e, &request))?;
+
+    Ok(HttpResponse::Ok().json(pokemon_translator.explain(&pokemon)))
+}
@@ -0,0 +1,33 @@
+use std::collections::BTreeMap;
+
+use actix_web::{web, HttpResponse};
+use serde::Serialize;
+
+use crate::pokemon_bounded_context::adapter::out::{
+    CacheBackend, CacheStatus, Circuit, CircuitStatus, QuotaStatus, TranslationQuota,
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Readiness {
+    cache: CacheStatus,
+    translation_quota: QuotaStatus,
+    circuits: BTreeMap<String, CircuitStatus>,
+}
+
+/// The service is ready even without cache, translation quota or upstream services,
+/// which only report their status.
+pub async fn readiness(
+    cache: web::Data<CacheBackend>,
+    translation_quota: web::Data<TranslationQuota>,
+    circuits: web::Data<Vec<Circuit>>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(Readiness {
+        cache: cache.status(),
+        translation_quota: translation_quota.status(),
+        circuits: circuits
+            .iter()
+            .map(|circuit| (circuit.name().to_string(), circuit.status()))
+            .collect(),
+    })
+}
@@ -1,3 +1,25 @@
+pub use cache_key::CacheKey;
+pub use error::{validate_name, PokemonError};
+pub use evolution::{EvolutionChain, EvolutionCondition, EvolutionSpecies};
+pub use language::{fallback_chain, Language};
 pub use pokemon::Pokemon;
+pub use pokemon_details::{Ability, PokemonDetails, Stat};
+pub use pokemon_lookup::PokemonLookup;
+pub use pokemon_name::{closest_names, normalize_name};
+pub use species_listing::{PageRequest, SpeciesFilter, SpeciesPage, SpeciesSummary};
+pub use translation::{Translated, Translation, TranslationSource, UntranslatedReason};
+pub use translation_rules::{RuleConditions, RuleMatch, TranslationRule, TranslationRules};
+pub use translation_style::TranslationStyle;
 
+mod cache_key;
+mod error;
+mod evolution;
+mod language;
 mod pokemon;
+mod pokemon_details;
+mod pokemon_lookup;
+mod pokemon_name;
+mod species_listing;
+mod translation;
+mod translation_rules;
+mod translation_style;
@@ -0,0 +1,129 @@
+use std::fmt::{Display, Formatter};
+
+use crate::pokemon_bounded_context::domain::{Language, PokemonLookup, TranslationStyle};
+
+/// Version of the cached pokemon format: bump it whenever `Pokemon` serializes differently.
+const POKEMON_VERSION: u32 = 2;
+
+/// Identifies a translated description or a retrieved pokemon inside the cache.
+///
+/// Bumping the rules `version` invalidates all the translations cached with the previous one.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CacheKey(Entry);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Entry {
+    Translation {
+        pokemon_name: String,
+        style: TranslationStyle,
+        language: Option<Language>,
+        version: u32,
+    },
+    Pokemon {
+        lookup: PokemonLookup,
+        languages: Vec<Language>,
+    },
+}
+
+impl CacheKey {
+    pub fn translation(
+        pokemon_name: String,
+        style: TranslationStyle,
+        language: Option<Language>,
+        version: u32,
+    ) -> Self {
+        CacheKey(Entry::Translation {
+            pokemon_name,
+            style,
+            language,
+            version,
+        })
+    }
+    /// The pokemon retrieved with its description in the first available of the `languages`.
+    pub fn pokemon(lookup: PokemonLookup, languages: Vec<Language>) -> Self {
+        CacheKey(Entry::Pokemon { lookup, languages })
+    }
+}
+
+impl Display for CacheKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Entry::Translation {
+                pokemon_name,
+                style,
+                language,
+                version,
+            } => write!(
+                f,
+                "translation:v{}:{}:{}:{}",
+                version,
+                style,
+                language.as_ref().map_or("-", |l| l.as_str()),
+                pokemon_name
+            ),
+            Entry::Pokemon { lookup, languages } if languages.is_empty() => {
+                write!(f, "pokemon:v{}:-:{}", POKEMON_VERSION, lookup)
+            }
+            Entry::Pokemon { lookup, languages } => write!(
+                f,
+                "pokemon:v{}:{}:{}",
+                POKEMON_VERSION,
+                languages
+                    .iter()
+                    .map(Language::as_str)
+                    .collect::<Vec<&str>>()
+                    .join(","),
+                lookup
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_key_includes_version_style_and_language() {
+        assert_eq!(
+            "translation:v2:yoda:fr:mewtwo",
+            CacheKey::translation(
+                "mewtwo".to_string(),
+                TranslationStyle::yoda(),
+                Some(Language::new("fr")),
+                2
+            )
+            .to_string()
+        );
+        assert_eq!(
+            "translation:v1:shakespeare:-:mewtwo",
+            CacheKey::translation(
+                "mewtwo".to_string(),
+                TranslationStyle::shakespeare(),
+                None,
+                1
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn cache_key_of_a_pokemon_includes_the_languages_and_the_lookup() {
+        assert_eq!(
+            "pokemon:v2:fr-CA,fr,en:mewtwo",
+            CacheKey::pokemon(
+                PokemonLookup::ByName("mewtwo".to_string()),
+                vec![
+                    Language::new("fr-CA"),
+                    Language::new("fr"),
+                    Language::new("en")
+                ]
+            )
+            .to_string()
+        );
+        assert_eq!(
+            "pokemon:v2:-:#150",
+            CacheKey::pokemon(PokemonLookup::ById(150), vec![]).to_string()
+        );
+    }
+}
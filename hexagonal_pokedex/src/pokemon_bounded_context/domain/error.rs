@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+/// Failures that the ports report to the domain.
+///
+/// The upstream variants keep their cause as `source`, so it can be logged
+/// without becoming part of the message.
+#[derive(thiserror::Error, Debug)]
+pub enum PokemonError {
+    #[error("Pokemon not found")]
+    NotFound,
+    /// Not found, with the known names closest to the requested one.
+    #[error("Pokemon not found, did you mean: {}?", .0.join(", "))]
+    NotFoundSimilarTo(Vec<String>),
+    #[error("Invalid pokemon name: {0}")]
+    InvalidName(String),
+    #[error("Invalid pokedex number: {0}")]
+    InvalidId(String),
+    #[error("Unknown translation style: {0}")]
+    UnknownStyle(String),
+    #[error("Too many pokemon requested, the maximum is {0}")]
+    BatchTooLarge(usize),
+    #[error("Invalid page: {0}")]
+    InvalidPage(String),
+    #[error("Invalid language tag: {0}")]
+    InvalidLanguage(String),
+    #[error("The upstream service rate limit has been exceeded")]
+    RateLimited,
+    #[error("The upstream service did not answer in time")]
+    UpstreamTimeout(#[source] anyhow::Error),
+    #[error("The upstream service is unavailable")]
+    UpstreamUnavailable(#[source] anyhow::Error),
+}
+
+/// The error of a call shared by concurrent callers, as reported to each of them.
+///
+/// The upstream variants reported to the callers that did not run the call
+/// have the shared error as cause, so its chain is kept.
+impl From<Arc<PokemonError>> for PokemonError {
+    fn from(shared: Arc<PokemonError>) -> Self {
+        let shared = match Arc::try_unwrap(shared) {
+            Ok(error) => return error,
+            Err(shared) => shared,
+        };
+        match shared.as_ref() {
+            PokemonError::NotFound => PokemonError::NotFound,
+            PokemonError::NotFoundSimilarTo(names) => {
+                PokemonError::NotFoundSimilarTo(names.clone())
+            }
+            PokemonError::InvalidName(name) => PokemonError::InvalidName(name.clone()),
+            PokemonError::InvalidId(id) => PokemonError::InvalidId(id.clone()),
+            PokemonError::UnknownStyle(style) => PokemonError::UnknownStyle(style.clone()),
+            PokemonError::BatchTooLarge(size) => PokemonError::BatchTooLarge(*size),
+            PokemonError::InvalidPage(reason) => PokemonError::InvalidPage(reason.clone()),
+            PokemonError::InvalidLanguage(tag) => PokemonError::InvalidLanguage(tag.clone()),
+            PokemonError::RateLimited => PokemonError::RateLimited,
+            PokemonError::UpstreamTimeout(_) => {
+                PokemonError::UpstreamTimeout(anyhow::Error::new(shared.clone()))
+            }
+            PokemonError::UpstreamUnavailable(_) => {
+                PokemonError::UpstreamUnavailable(anyhow::Error::new(shared.clone()))
+            }
+        }
+    }
+}
+
+const MAX_NAME_LENGTH: usize = 64;
+
+/// Reject names that cannot identify a pokemon, before calling any port.
+pub fn validate_name(pokemon_name: &str) -> Result<(), PokemonError> {
+    let is_valid = !pokemon_name.trim().is_empty()
+        && pokemon_name.chars().count() <= MAX_NAME_LENGTH
+        && pokemon_name
+            .chars()
+            .all(|c| c.is_alphanumeric() || "-_.': ".contains(c));
+    if is_valid {
+        Ok(())
+    } else {
+        Err(PokemonError::InvalidName(pokemon_name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_name_accepts_pokemon_names() {
+        for name in &["mewtwo", "mr-mime", "farfetch'd", "type: null", "mime jr."] {
+            assert!(validate_name(name).is_ok());
+        }
+    }
+
+    #[test]
+    fn shared_upstream_errors_keep_their_cause_chain() {
+        let shared = Arc::new(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+            "connection reset"
+        )));
+        let _waiter = shared.clone();
+
+        let error = PokemonError::from(shared);
+
+        assert!(matches!(error, PokemonError::UpstreamUnavailable(_)));
+        assert!(format!("{:?}", anyhow::Error::new(error)).contains("connection reset"));
+    }
+
+    #[test]
+    fn validate_name_rejects_invalid_names() {
+        for name in &["", "  ", "pika/chu", "{name}", &"a".repeat(65)] {
+            assert!(matches!(
+                validate_name(name),
+                Err(PokemonError::InvalidName(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::pokemon_bounded_context::domain::PokemonError;
+
+/// What is needed to evolve into a species, e.g. `level-up` at `min_level` 16.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvolutionCondition {
+    trigger: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_level: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_happiness: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    held_item: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    known_move: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_of_day: Option<String>,
+}
+
+impl EvolutionCondition {
+    pub fn new(trigger: String) -> Self {
+        EvolutionCondition {
+            trigger,
+            ..Default::default()
+        }
+    }
+    pub fn with_min_level(self, min_level: Option<i64>) -> Self {
+        Self { min_level, ..self }
+    }
+    pub fn with_min_happiness(self, min_happiness: Option<i64>) -> Self {
+        Self {
+            min_happiness,
+            ..self
+        }
+    }
+    pub fn with_item(self, item: Option<String>) -> Self {
+        Self { item, ..self }
+    }
+    pub fn with_held_item(self, held_item: Option<String>) -> Self {
+        Self { held_item, ..self }
+    }
+    pub fn with_known_move(self, known_move: Option<String>) -> Self {
+        Self { known_move, ..self }
+    }
+    pub fn with_time_of_day(self, time_of_day: Option<String>) -> Self {
+        Self {
+            time_of_day: time_of_day.filter(|t| !t.is_empty()),
+            ..self
+        }
+    }
+}
+
+/// A member of an evolution chain, as stored by PokeAPI: it only knows its predecessor.
+pub struct EvolutionSpecies {
+    id: i64,
+    name: String,
+    evolves_from: Option<i64>,
+    conditions: Vec<EvolutionCondition>,
+}
+
+impl EvolutionSpecies {
+    pub fn new(
+        id: i64,
+        name: String,
+        evolves_from: Option<i64>,
+        conditions: Vec<EvolutionCondition>,
+    ) -> Self {
+        EvolutionSpecies {
+            id,
+            name,
+            evolves_from,
+            conditions,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvolutionNode {
+    name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    conditions: Vec<EvolutionCondition>,
+    evolves_to: Vec<EvolutionNode>,
+}
+
+/// The evolution tree of a family of species, from its base species.
+///
+/// A species can evolve into many others, e.g. eevee.
+#[derive(Debug, PartialEq, serde::Serialize)]
+#[serde(transparent)]
+pub struct EvolutionChain {
+    root: EvolutionNode,
+}
+
+impl EvolutionChain {
+    /// Build the tree from the members of the chain.
+    ///
+    /// The base species is the member that does not evolve from another member of the chain.
+    pub fn from_species(species: Vec<EvolutionSpecies>) -> Result<Self, PokemonError> {
+        let is_member = |id: i64| species.iter().any(|s| s.id == id);
+        let root = species
+            .iter()
+            .find(|s| !s.evolves_from.is_some_and(is_member))
+            .ok_or_else(|| {
+                PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                    "Evolution chain without a base species"
+                ))
+            })?;
+        Ok(EvolutionChain {
+            root: evolution_node(root, &species),
+        })
+    }
+}
+
+fn evolution_node(current: &EvolutionSpecies, species: &[EvolutionSpecies]) -> EvolutionNode {
+    EvolutionNode {
+        name: current.name.clone(),
+        conditions: current.conditions.clone(),
+        evolves_to: species
+            .iter()
+            .filter(|s| s.evolves_from == Some(current.id))
+            .map(|s| evolution_node(s, species))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn evolution_chain_is_built_from_the_base_species() {
+        let chain = EvolutionChain::from_species(vec![
+            EvolutionSpecies::new(
+                2,
+                "ivysaur".to_string(),
+                Some(1),
+                vec![EvolutionCondition::new("level-up".to_string()).with_min_level(Some(16))],
+            ),
+            EvolutionSpecies::new(1, "bulbasaur".to_string(), None, vec![]),
+            EvolutionSpecies::new(
+                3,
+                "venusaur".to_string(),
+                Some(2),
+                vec![EvolutionCondition::new("level-up".to_string()).with_min_level(Some(32))],
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            json!({
+                "name": "bulbasaur",
+                "evolvesTo": [{
+                    "name": "ivysaur",
+                    "conditions": [{ "trigger": "level-up", "minLevel": 16 }],
+                    "evolvesTo": [{
+                        "name": "venusaur",
+                        "conditions": [{ "trigger": "level-up", "minLevel": 32 }],
+                        "evolvesTo": []
+                    }]
+                }]
+            }),
+            json!(chain)
+        );
+    }
+
+    #[test]
+    fn evolution_chain_supports_branches() {
+        let chain = EvolutionChain::from_species(vec![
+            EvolutionSpecies::new(133, "eevee".to_string(), None, vec![]),
+            EvolutionSpecies::new(
+                134,
+                "vaporeon".to_string(),
+                Some(133),
+                vec![EvolutionCondition::new("use-item".to_string())
+                    .with_item(Some("water-stone".to_string()))],
+            ),
+            EvolutionSpecies::new(
+                196,
+                "espeon".to_string(),
+                Some(133),
+                vec![EvolutionCondition::new("level-up".to_string())
+                    .with_min_happiness(Some(160))
+                    .with_time_of_day(Some("day".to_string()))],
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            json!({
+                "name": "eevee",
+                "evolvesTo": [
+                    {
+                        "name": "vaporeon",
+                        "conditions": [{ "trigger": "use-item", "item": "water-stone" }],
+                        "evolvesTo": []
+                    },
+                    {
+                        "name": "espeon",
+                        "conditions": [
+                            { "trigger": "level-up", "minHappiness": 160, "timeOfDay": "day" }
+                        ],
+                        "evolvesTo": []
+                    }
+                ]
+            }),
+            json!(chain)
+        );
+    }
+
+    #[test]
+    fn evolution_chain_without_members_is_rejected() {
+        assert!(matches!(
+            EvolutionChain::from_species(vec![]),
+            Err(PokemonError::UpstreamUnavailable(_))
+        ));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::pokemon_bounded_context::domain::PokemonError;
+
+const MAX_SUBTAG_LENGTH: usize = 8;
+
+/// A language tag in the form used by PokeAPI (e.g. `en`, `fr`, `ja-Hrkt`).
+///
+/// Subtags are normalised to the canonical BCP 47 casing,
+/// so `FR-ca` and `fr-CA` identify the same language.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Language(String);
+
+impl Language {
+    /// Reject the tags that are empty or not made of 1 to 8 alphanumeric characters subtags.
+    pub fn parse(tag: &str) -> Result<Self, PokemonError> {
+        let is_valid = tag.trim().split(['-', '_']).all(|subtag| {
+            (1..=MAX_SUBTAG_LENGTH).contains(&subtag.len())
+                && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+        if is_valid {
+            Ok(Language::new(tag))
+        } else {
+            Err(PokemonError::InvalidLanguage(tag.to_string()))
+        }
+    }
+
+    pub fn new(tag: &str) -> Self {
+        let tag = tag
+            .trim()
+            .split(['-', '_'])
+            .enumerate()
+            .map(|(index, subtag)| match (index, subtag.len()) {
+                (0, _) => subtag.to_ascii_lowercase(),
+                (_, 2) => subtag.to_ascii_uppercase(),
+                (_, 4) => {
+                    let (first, rest) = subtag.split_at(1);
+                    first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+                }
+                _ => subtag.to_ascii_lowercase(),
+            })
+            .collect::<Vec<String>>()
+            .join("-");
+        Language(tag)
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    /// The tag without its last subtag: `fr-CA` -> `fr`.
+    pub fn parent(&self) -> Option<Language> {
+        self.0
+            .rsplit_once('-')
+            .map(|(parent, _)| Language(parent.to_string()))
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Build the ordered list of languages to try for a description.
+///
+/// Every requested language is followed by its parents,
+/// and the configured fallback languages are tried last: `fr-CA -> fr -> en`.
+pub fn fallback_chain(requested: &[Language], fallback: &[Language]) -> Vec<Language> {
+    let mut chain: Vec<Language> = vec![];
+    for language in requested {
+        let mut current = Some(language.clone());
+        while let Some(language) = current {
+            current = language.parent();
+            if !chain.contains(&language) {
+                chain.push(language);
+            }
+        }
+    }
+    for language in fallback {
+        if !chain.contains(language) {
+            chain.push(language.clone());
+        }
+    }
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_normalises_subtags_casing() {
+        assert_eq!("fr-CA", Language::new(" FR_ca ").as_str());
+        assert_eq!("ja-Hrkt", Language::new("JA-HRKT").as_str());
+        assert_eq!("zh-Hant-TW", Language::new("zh-hant-tw").as_str());
+    }
+
+    #[test]
+    fn language_rejects_empty_and_malformed_tags() {
+        assert_eq!(Language::new("fr-CA"), Language::parse("fr_ca").unwrap());
+        for tag in &["", " ", "-", "fr-", "en--US", "fr CA", "toolongsubtag"] {
+            assert!(matches!(
+                Language::parse(tag),
+                Err(PokemonError::InvalidLanguage(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn fallback_chain_tries_parents_before_fallback_languages() {
+        let chain = fallback_chain(
+            &[Language::new("fr-CA"), Language::new("de")],
+            &[Language::new("en"), Language::new("fr")],
+        );
+        assert_eq!(
+            vec![
+                Language::new("fr-CA"),
+                Language::new("fr"),
+                Language::new("de"),
+                Language::new("en")
+            ],
+            chain
+        );
+    }
+
+    #[test]
+    fn fallback_chain_without_requested_languages_is_the_fallback() {
+        assert_eq!(
+            vec![Language::new("en")],
+            fallback_chain(&[], &[Language::new("en")])
+        );
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(serde::Serialize)]
+use crate::pokemon_bounded_context::domain::{Language, PokemonDetails, Translation};
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Pokemon {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6,7 +8,16 @@ pub struct Pokemon {
     #[serde(skip_serializing_if = "Option::is_none")]
     habitat: Option<String>,
     is_legendary: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<Language>,
     name: String,
+    /// The national Pokédex number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
+    translation: Option<Translation>,
+    #[serde(flatten)]
+    details: PokemonDetails,
 }
 
 impl Pokemon {
@@ -14,30 +25,60 @@ impl Pokemon {
         description: Option<String>,
         habitat: Option<String>,
         is_legendary: bool,
+        language: Option<Language>,
         name: String,
     ) -> Self {
         Pokemon {
             description,
             habitat,
             is_legendary,
+            language,
             name,
+            id: None,
+            translation: None,
+            details: PokemonDetails::default(),
         }
     }
     pub fn description(&self) -> &Option<String> {
         &self.description
     }
+    pub fn language(&self) -> &Option<Language> {
+        &self.language
+    }
+    pub fn translation(&self) -> &Option<Translation> {
+        &self.translation
+    }
     pub fn name(&self) -> &str {
         &self.name
     }
-    pub fn is_cave_or_legendary(&self) -> bool {
-        self.habitat.as_deref().eq(&Some("cave")) || self.is_legendary
+    pub fn habitat(&self) -> &Option<String> {
+        &self.habitat
+    }
+    pub fn is_legendary(&self) -> bool {
+        self.is_legendary
+    }
+    pub fn details(&self) -> &PokemonDetails {
+        &self.details
     }
     pub fn with_description(self, description: String) -> Self {
         Self {
             description: Some(description),
-            habitat: self.habitat,
-            is_legendary: self.is_legendary,
-            name: self.name,
+            ..self
+        }
+    }
+    pub fn with_id(self, id: u32) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+    pub fn with_details(self, details: PokemonDetails) -> Self {
+        Self { details, ..self }
+    }
+    pub fn with_translation(self, translation: Translation) -> Self {
+        Self {
+            translation: Some(translation),
+            ..self
         }
     }
 }
@@ -0,0 +1,77 @@
+/// Characteristics of the species and of its default variety.
+///
+/// `height` is in decimetres and `weight` in hectograms, as reported by PokeAPI.
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PokemonDetails {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    types: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stats: Vec<Stat>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    abilities: Vec<Ability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<i64>,
+    is_mythical: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation: Option<String>,
+}
+
+impl PokemonDetails {
+    pub fn new(
+        types: Vec<String>,
+        stats: Vec<Stat>,
+        abilities: Vec<Ability>,
+        height: Option<i64>,
+        weight: Option<i64>,
+        is_mythical: bool,
+        generation: Option<String>,
+    ) -> Self {
+        PokemonDetails {
+            types,
+            stats,
+            abilities,
+            height,
+            weight,
+            is_mythical,
+            generation,
+        }
+    }
+    pub fn types(&self) -> &[String] {
+        &self.types
+    }
+    pub fn is_mythical(&self) -> bool {
+        self.is_mythical
+    }
+    pub fn generation(&self) -> &Option<String> {
+        &self.generation
+    }
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stat {
+    name: String,
+    base_stat: i64,
+}
+
+impl Stat {
+    pub fn new(name: String, base_stat: i64) -> Self {
+        Stat { name, base_stat }
+    }
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ability {
+    name: String,
+    is_hidden: bool,
+}
+
+impl Ability {
+    pub fn new(name: String, is_hidden: bool) -> Self {
+        Ability { name, is_hidden }
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::{Display, Formatter};
+
+use crate::pokemon_bounded_context::domain::{normalize_name, PokemonError};
+
+/// The largest Pokédex number PokeAPI can look up, as its ids are signed 32 bits integers.
+const MAX_ID: u32 = i32::MAX as u32;
+
+/// How a pokemon is looked up: by its normalized name or by its national Pokédex number.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PokemonLookup {
+    ByName(String),
+    ById(u32),
+}
+
+impl PokemonLookup {
+    /// A path segment made only of digits is a Pokédex number, anything else a name.
+    pub fn parse(name_or_id: &str) -> Result<Self, PokemonError> {
+        if !name_or_id.is_empty() && name_or_id.chars().all(|c| c.is_ascii_digit()) {
+            Self::by_id(name_or_id)
+        } else {
+            normalize_name(name_or_id).map(PokemonLookup::ByName)
+        }
+    }
+
+    /// Pokédex numbers start at 1.
+    pub fn by_id(id: &str) -> Result<Self, PokemonError> {
+        match id.parse() {
+            Ok(id) if id > 0 && id <= MAX_ID => Ok(PokemonLookup::ById(id)),
+            _ => Err(PokemonError::InvalidId(id.to_string())),
+        }
+    }
+}
+
+impl Display for PokemonLookup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PokemonLookup::ByName(name) => write!(f, "{}", name),
+            PokemonLookup::ById(id) => write!(f, "#{}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pokemon_lookup_parses_ids_and_names() {
+        assert_eq!(PokemonLookup::ById(25), PokemonLookup::parse("25").unwrap());
+        assert_eq!(
+            PokemonLookup::ByName("porygon2".to_string()),
+            PokemonLookup::parse("Porygon2").unwrap()
+        );
+    }
+
+    #[test]
+    fn pokemon_lookup_rejects_invalid_ids() {
+        for id in &["0", "-1", "pikachu", "2147483648", "99999999999"] {
+            assert!(matches!(
+                PokemonLookup::by_id(id),
+                Err(PokemonError::InvalidId(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::pokemon_bounded_context::domain::{validate_name, PokemonError};
+
+/// Spellings that the normalisation alone cannot turn into the PokeAPI name.
+const ALIASES: &[(&str, &str)] = &[
+    ("mrmime", "mr-mime"),
+    ("mrrime", "mr-rime"),
+    ("mimejr", "mime-jr"),
+    ("hooh", "ho-oh"),
+    ("porygonz", "porygon-z"),
+    ("typenull", "type-null"),
+    ("jangmoo", "jangmo-o"),
+    ("hakamoo", "hakamo-o"),
+    ("kommoo", "kommo-o"),
+    ("nidoran-female", "nidoran-f"),
+    ("nidoran-male", "nidoran-m"),
+    ("flabébé", "flabebe"),
+];
+
+/// Turn the name typed by a client into the PokeAPI name of the species:
+/// lowercase, words joined by `-`, without punctuation, and with the known aliases resolved.
+pub fn normalize_name(pokemon_name: &str) -> Result<String, PokemonError> {
+    validate_name(pokemon_name)?;
+    let mut normalized = String::with_capacity(pokemon_name.len());
+    for c in pokemon_name.trim().chars() {
+        match c {
+            ' ' | '_' | '-' => {
+                if !normalized.is_empty() && !normalized.ends_with('-') {
+                    normalized.push('-');
+                }
+            }
+            '.' | '\'' | ':' => {}
+            c => normalized.extend(c.to_lowercase()),
+        }
+    }
+    let normalized = normalized.trim_end_matches('-');
+    if normalized.is_empty() {
+        return Err(PokemonError::InvalidName(pokemon_name.to_string()));
+    }
+    Ok(ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == normalized)
+        .map_or(normalized, |(_, name)| name)
+        .to_string())
+}
+
+/// The `max` known names closest to the normalized `pokemon_name` by edit distance,
+/// ignoring the ones too different to be a typo.
+pub fn closest_names(pokemon_name: &str, known_names: &[String], max: usize) -> Vec<String> {
+    let max_distance = 1 + pokemon_name.chars().count() / 4;
+    let mut candidates: Vec<(usize, &String)> = known_names
+        .iter()
+        .map(|known_name| (edit_distance(pokemon_name, known_name), known_name))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    candidates.sort_unstable();
+    candidates
+        .into_iter()
+        .take(max)
+        .map(|(_, known_name)| known_name.clone())
+        .collect()
+}
+
+/// Levenshtein distance, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = Vec::with_capacity(b.len() + 1);
+        current.push(i + 1);
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_name_returns_the_pokeapi_name() {
+        for (name, normalized) in &[
+            ("Pikachu", "pikachu"),
+            ("  pikachu ", "pikachu"),
+            ("mr mime", "mr-mime"),
+            ("Mr. Mime", "mr-mime"),
+            ("MrMime", "mr-mime"),
+            ("farfetch'd", "farfetchd"),
+            ("type: null", "type-null"),
+            ("mime_jr.", "mime-jr"),
+            ("ho-oh", "ho-oh"),
+        ] {
+            assert_eq!(normalized, &normalize_name(name).unwrap());
+        }
+    }
+
+    #[test]
+    fn normalize_name_rejects_names_without_letters() {
+        for name in &["...", "pika/chu", " - "] {
+            assert!(matches!(
+                normalize_name(name),
+                Err(PokemonError::InvalidName(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn closest_names_returns_the_most_similar_known_names() {
+        let known_names: Vec<String> = ["pikachu", "raichu", "pichu", "bulbasaur"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            vec!["pikachu", "pichu"],
+            closest_names("pikachuu", &known_names, 2)
+        );
+        assert!(closest_names("charizard", &known_names, 2).is_empty());
+    }
+
+    #[test]
+    fn edit_distance_counts_the_edits() {
+        assert_eq!(0, edit_distance("mew", "mew"));
+        assert_eq!(3, edit_distance("mew", "mewtwo"));
+        assert_eq!(1, edit_distance("pikachu", "pikachi"));
+        assert_eq!(2, edit_distance("eevee", "evee "));
+    }
+}
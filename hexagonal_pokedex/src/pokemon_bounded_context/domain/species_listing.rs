@@ -0,0 +1,116 @@
+use serde::Serialize;
+
+/// Criteria of the species listing: a species is listed when it matches all the given ones.
+///
+/// `name_contains` is a case insensitive search in the species name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpeciesFilter {
+    habitat: Option<String>,
+    is_legendary: Option<bool>,
+    pokemon_type: Option<String>,
+    generation: Option<String>,
+    name_contains: Option<String>,
+}
+
+/// The slice of the listing to retrieve, in Pokédex order:
+/// at most `limit` species after the species `after_id`, skipping the first `offset`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageRequest {
+    limit: u32,
+    offset: u32,
+    after_id: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SpeciesSummary {
+    id: u32,
+    name: String,
+}
+
+/// A page of the listing, with the number of species matching the filter over all the pages.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeciesPage {
+    total: u64,
+    results: Vec<SpeciesSummary>,
+    /// Resumes the listing after this page, missing on the last one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<String>,
+}
+
+impl SpeciesFilter {
+    pub fn new(
+        habitat: Option<String>,
+        is_legendary: Option<bool>,
+        pokemon_type: Option<String>,
+        generation: Option<String>,
+        name_contains: Option<String>,
+    ) -> Self {
+        SpeciesFilter {
+            habitat,
+            is_legendary,
+            pokemon_type,
+            generation,
+            name_contains,
+        }
+    }
+    pub fn habitat(&self) -> Option<&str> {
+        self.habitat.as_deref()
+    }
+    pub fn is_legendary(&self) -> Option<bool> {
+        self.is_legendary
+    }
+    pub fn pokemon_type(&self) -> Option<&str> {
+        self.pokemon_type.as_deref()
+    }
+    pub fn generation(&self) -> Option<&str> {
+        self.generation.as_deref()
+    }
+    pub fn name_contains(&self) -> Option<&str> {
+        self.name_contains.as_deref()
+    }
+}
+
+impl PageRequest {
+    pub fn new(limit: u32, offset: u32, after_id: Option<u32>) -> Self {
+        PageRequest {
+            limit,
+            offset,
+            after_id,
+        }
+    }
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+    pub fn after_id(&self) -> Option<u32> {
+        self.after_id
+    }
+    pub fn with_limit(self, limit: u32) -> Self {
+        Self { limit, ..self }
+    }
+}
+
+impl SpeciesSummary {
+    pub fn new(id: u32, name: String) -> Self {
+        SpeciesSummary { id, name }
+    }
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl SpeciesPage {
+    pub fn new(total: u64, results: Vec<SpeciesSummary>, next_cursor: Option<String>) -> Self {
+        SpeciesPage {
+            total,
+            results,
+            next_cursor,
+        }
+    }
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+}
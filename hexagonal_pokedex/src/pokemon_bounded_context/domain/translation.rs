@@ -0,0 +1,68 @@
+/// Whether the description of a pokemon has been translated and, if not, why.
+#[derive(Clone, serde::Serialize)]
+pub struct Translation {
+    applied: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<TranslationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<UntranslatedReason>,
+}
+
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UntranslatedReason {
+    MissingDescription,
+    RateLimited,
+    TranslatorError,
+}
+
+impl Translation {
+    pub fn applied(source: TranslationSource) -> Self {
+        Translation {
+            applied: true,
+            source: Some(source),
+            reason: None,
+        }
+    }
+    pub fn skipped(reason: UntranslatedReason) -> Self {
+        Translation {
+            applied: false,
+            source: None,
+            reason: Some(reason),
+        }
+    }
+    pub fn is_applied(&self) -> bool {
+        self.applied
+    }
+}
+
+/// A text translated by a translator, with the translator that produced it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Translated {
+    text: String,
+    source: TranslationSource,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslationSource {
+    Remote,
+    Local,
+    /// The local translator, standing in for the remote one that failed.
+    LocalFallback,
+}
+
+impl Translated {
+    pub fn new(text: String, source: TranslationSource) -> Self {
+        Translated { text, source }
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn source(&self) -> TranslationSource {
+        self.source
+    }
+    pub fn into_text(self) -> String {
+        self.text
+    }
+}
@@ -0,0 +1,235 @@
+use crate::pokemon_bounded_context::domain::{Pokemon, TranslationStyle};
+
+/// Predicates over the pokemon: a missing predicate matches every pokemon.
+///
+/// Text predicates are case insensitive.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleConditions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    habitat: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_legendary: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_mythical: Option<bool>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pokemon_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation: Option<String>,
+}
+
+impl RuleConditions {
+    pub fn new(
+        habitat: Option<String>,
+        is_legendary: Option<bool>,
+        is_mythical: Option<bool>,
+        pokemon_type: Option<String>,
+        generation: Option<String>,
+    ) -> Self {
+        RuleConditions {
+            habitat,
+            is_legendary,
+            is_mythical,
+            pokemon_type,
+            generation,
+        }
+    }
+    fn matches(&self, pokemon: &Pokemon) -> bool {
+        let same_text = |expected: &Option<String>, actual: Option<&String>| {
+            expected
+                .as_ref()
+                .is_none_or(|e| actual.is_some_and(|a| a.eq_ignore_ascii_case(e)))
+        };
+        same_text(&self.habitat, pokemon.habitat().as_ref())
+            && self
+                .is_legendary
+                .is_none_or(|l| l == pokemon.is_legendary())
+            && self
+                .is_mythical
+                .is_none_or(|m| m == pokemon.details().is_mythical())
+            && self.pokemon_type.as_ref().is_none_or(|t| {
+                pokemon
+                    .details()
+                    .types()
+                    .iter()
+                    .any(|pokemon_type| pokemon_type.eq_ignore_ascii_case(t))
+            })
+            && same_text(&self.generation, pokemon.details().generation().as_ref())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationRule {
+    name: String,
+    when: RuleConditions,
+    style: TranslationStyle,
+}
+
+impl TranslationRule {
+    pub fn new(name: String, when: RuleConditions, style: TranslationStyle) -> Self {
+        TranslationRule { name, when, style }
+    }
+}
+
+/// The rule that selected the translation style of a pokemon.
+///
+/// Without a matching rule the default style is selected.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleMatch {
+    rule: Option<TranslationRule>,
+    style: TranslationStyle,
+}
+
+impl RuleMatch {
+    pub fn style(&self) -> &TranslationStyle {
+        &self.style
+    }
+}
+
+/// Ordered list of rules selecting the translation style: the first matching rule wins.
+#[derive(Clone, Debug)]
+pub struct TranslationRules {
+    rules: Vec<TranslationRule>,
+    default_style: TranslationStyle,
+}
+
+impl TranslationRules {
+    pub fn new(rules: Vec<TranslationRule>, default_style: TranslationStyle) -> Self {
+        TranslationRules {
+            rules,
+            default_style,
+        }
+    }
+    pub fn select(&self, pokemon: &Pokemon) -> RuleMatch {
+        match self.rules.iter().find(|rule| rule.when.matches(pokemon)) {
+            Some(rule) => RuleMatch {
+                rule: Some(rule.clone()),
+                style: rule.style.clone(),
+            },
+            None => RuleMatch {
+                rule: None,
+                style: self.default_style.clone(),
+            },
+        }
+    }
+    /// Every style that can be selected by the rules.
+    pub fn styles(&self) -> impl Iterator<Item = &TranslationStyle> {
+        self.rules
+            .iter()
+            .map(|rule| &rule.style)
+            .chain(std::iter::once(&self.default_style))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::pokemon_bounded_context::domain::PokemonDetails;
+
+    use super::*;
+
+    #[test]
+    fn translation_rules_select_the_first_matching_rule() {
+        let rules = rules();
+        let pokemon = pokemon(Some("cave"), true, false, &["rock"]);
+        assert_eq!(
+            json!({
+                "rule": { "name": "cave", "when": { "habitat": "cave" }, "style": "yoda" },
+                "style": "yoda"
+            }),
+            json!(rules.select(&pokemon))
+        );
+    }
+
+    #[test]
+    fn translation_rules_require_all_the_conditions_of_a_rule() {
+        let rules = rules();
+        assert_eq!(
+            &TranslationStyle::new("pirate"),
+            rules
+                .select(&pokemon(Some("sea"), false, false, &["water"]))
+                .style()
+        );
+        assert_eq!(
+            &TranslationStyle::shakespeare(),
+            rules
+                .select(&pokemon(Some("sea"), false, false, &["fire"]))
+                .style()
+        );
+    }
+
+    #[test]
+    fn translation_rules_fall_back_to_the_default_style() {
+        let rules = rules();
+        assert_eq!(
+            json!({ "rule": null, "style": "shakespeare" }),
+            json!(rules.select(&pokemon(None, false, false, &[])))
+        );
+    }
+
+    #[test]
+    fn translation_rules_match_mythical_pokemon() {
+        assert_eq!(
+            &TranslationStyle::yoda(),
+            rules()
+                .select(&pokemon(Some("rare"), false, true, &["psychic"]))
+                .style()
+        );
+    }
+
+    fn rules() -> TranslationRules {
+        TranslationRules::new(
+            vec![
+                TranslationRule::new(
+                    "cave".to_string(),
+                    RuleConditions::new(Some("cave".to_string()), None, None, None, None),
+                    TranslationStyle::yoda(),
+                ),
+                TranslationRule::new(
+                    "mythical".to_string(),
+                    RuleConditions::new(None, None, Some(true), None, None),
+                    TranslationStyle::yoda(),
+                ),
+                TranslationRule::new(
+                    "sea".to_string(),
+                    RuleConditions::new(
+                        Some("Sea".to_string()),
+                        None,
+                        None,
+                        Some("water".to_string()),
+                        None,
+                    ),
+                    TranslationStyle::new("pirate"),
+                ),
+            ],
+            TranslationStyle::shakespeare(),
+        )
+    }
+
+    fn pokemon(
+        habitat: Option<&str>,
+        is_legendary: bool,
+        is_mythical: bool,
+        types: &[&str],
+    ) -> Pokemon {
+        Pokemon::new(
+            None,
+            habitat.map(ToString::to_string),
+            is_legendary,
+            None,
+            "any_pokemon".to_string(),
+        )
+        .with_details(PokemonDetails::new(
+            types.iter().map(ToString::to_string).collect(),
+            vec![],
+            vec![],
+            None,
+            None,
+            is_mythical,
+            None,
+        ))
+    }
+}
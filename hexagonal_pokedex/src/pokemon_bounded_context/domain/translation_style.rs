@@ -0,0 +1,23 @@
+use std::fmt::{Display, Formatter};
+
+/// Identifier of a translation style, e.g. `yoda` or `pirate`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct TranslationStyle(String);
+
+impl TranslationStyle {
+    pub fn new(style: &str) -> Self {
+        TranslationStyle(style.trim().to_lowercase())
+    }
+    pub fn shakespeare() -> Self {
+        TranslationStyle::new("shakespeare")
+    }
+    pub fn yoda() -> Self {
+        TranslationStyle::new("yoda")
+    }
+}
+
+impl Display for TranslationStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
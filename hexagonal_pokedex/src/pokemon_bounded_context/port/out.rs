@@ -4,18 +4,26 @@ pub use cache_retrieval::MockCacheRetrieval;
 pub use cache_updater::CacheUpdater;
 #[cfg(test)]
 pub use cache_updater::MockCacheUpdater;
+pub use evolution_retrieval::EvolutionRetrieval;
+#[cfg(test)]
+pub use evolution_retrieval::MockEvolutionRetrieval;
 #[cfg(test)]
 pub use pokemon_retrieval::MockPokemonRetrieval;
 pub use pokemon_retrieval::PokemonRetrieval;
 #[cfg(test)]
-pub use shakespeare_translator::MockShakespeareTranslator;
-pub use shakespeare_translator::ShakespeareTranslator;
+pub use species_listing::MockSpeciesListing;
+pub use species_listing::SpeciesListing;
+#[cfg(test)]
+pub use species_retrieval::MockSpeciesRetrieval;
+pub use species_retrieval::SpeciesRetrieval;
 #[cfg(test)]
-pub use yoda_translator::MockYodaTranslator;
-pub use yoda_translator::YodaTranslator;
+pub use translator::MockTranslator;
+pub use translator::Translator;
 
 mod cache_retrieval;
 mod cache_updater;
+mod evolution_retrieval;
 mod pokemon_retrieval;
-mod shakespeare_translator;
-mod yoda_translator;
+mod species_listing;
+mod species_retrieval;
+mod translator;
@@ -1,5 +1,7 @@
+use crate::pokemon_bounded_context::domain::{CacheKey, PokemonError};
+
 #[cfg_attr(test, mockall::automock)]
 #[async_trait::async_trait]
 pub trait CacheRetrieval {
-    async fn get(&self, pokemon_name: &str) -> anyhow::Result<Option<String>>;
+    async fn get(&self, key: &CacheKey) -> Result<Option<String>, PokemonError>;
 }
@@ -1,5 +1,17 @@
+use std::time::Duration;
+
+use crate::pokemon_bounded_context::domain::{CacheKey, PokemonError};
+
 #[cfg_attr(test, mockall::automock)]
 #[async_trait::async_trait]
 pub trait CacheUpdater {
-    async fn update(&self, pokemon_name: &str, pokemon_description: String) -> anyhow::Result<()>;
+    async fn update(&self, key: &CacheKey, pokemon_description: String)
+        -> Result<(), PokemonError>;
+    /// Store the value for `ttl`, instead of the time to live of the cache.
+    async fn update_with_ttl(
+        &self,
+        key: &CacheKey,
+        value: String,
+        ttl: Duration,
+    ) -> Result<(), PokemonError>;
 }
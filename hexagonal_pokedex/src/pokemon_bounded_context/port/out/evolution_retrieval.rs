@@ -0,0 +1,8 @@
+use crate::pokemon_bounded_context::domain::{EvolutionChain, PokemonError};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait EvolutionRetrieval {
+    /// Retrieve the whole evolution chain the pokemon belongs to.
+    async fn get_evolutions(&self, pokemon_name: String) -> Result<EvolutionChain, PokemonError>;
+}
@@ -1,7 +1,20 @@
-use crate::pokemon_bounded_context::domain::Pokemon;
+use crate::pokemon_bounded_context::domain::{Language, Pokemon, PokemonError, PokemonLookup};
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait::async_trait]
 pub trait PokemonRetrieval {
-    async fn get(&self, pokemon_name: String) -> anyhow::Result<Pokemon>;
+    /// Retrieve the pokemon with the description in the first available language of `languages`.
+    async fn get(
+        &self,
+        lookup: PokemonLookup,
+        languages: Vec<Language>,
+    ) -> Result<Pokemon, PokemonError>;
+    /// Retrieve all the pokemon found among `pokemon_names`, in a single request.
+    ///
+    /// The names that do not match any pokemon are missing from the result.
+    async fn get_many(
+        &self,
+        pokemon_names: Vec<String>,
+        languages: Vec<Language>,
+    ) -> Result<Vec<Pokemon>, PokemonError>;
 }
@@ -0,0 +1,15 @@
+use crate::pokemon_bounded_context::domain::{
+    PageRequest, PokemonError, SpeciesFilter, SpeciesSummary,
+};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait SpeciesListing {
+    /// Retrieve the slice `page` of the species matching `filter`,
+    /// together with the number of all the species matching it.
+    async fn list(
+        &self,
+        filter: SpeciesFilter,
+        page: PageRequest,
+    ) -> Result<(Vec<SpeciesSummary>, u64), PokemonError>;
+}
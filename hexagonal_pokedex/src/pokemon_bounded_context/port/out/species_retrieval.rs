@@ -0,0 +1,8 @@
+use crate::pokemon_bounded_context::domain::PokemonError;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait SpeciesRetrieval {
+    /// Retrieve the names of all the known species.
+    async fn get_names(&self) -> Result<Vec<String>, PokemonError>;
+}
@@ -0,0 +1,17 @@
+use crate::pokemon_bounded_context::domain::{
+    PokemonError, Translated, TranslationSource, TranslationStyle,
+};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait Translator {
+    /// Whether the `style` is registered in the translator.
+    fn supports(&self, style: &TranslationStyle) -> bool;
+    /// The translator serving `style` when none fails, which produced its cached translations.
+    fn source(&self, style: &TranslationStyle) -> TranslationSource;
+    async fn translate(
+        &self,
+        style: &TranslationStyle,
+        text: &str,
+    ) -> Result<Translated, PokemonError>;
+}
@@ -1,5 +1,12 @@
+pub use pokemon_evolutions::PokemonEvolutions;
 pub use pokemon_info::PokemonInfo;
 pub use pokemon_translator::PokemonTranslator;
+pub use species_catalog::SpeciesCatalog;
+pub use species_names::SpeciesNames;
 
+mod pokemon_evolutions;
 mod pokemon_info;
 mod pokemon_translator;
+mod single_flight;
+mod species_catalog;
+mod species_names;
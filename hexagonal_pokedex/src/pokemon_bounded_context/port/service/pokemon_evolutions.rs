@@ -0,0 +1,71 @@
+use crate::pokemon_bounded_context::domain::{normalize_name, EvolutionChain, PokemonError};
+use crate::pokemon_bounded_context::port::out::EvolutionRetrieval;
+
+pub struct PokemonEvolutions<T>
+where
+    T: EvolutionRetrieval,
+{
+    evolution_retrieval: T,
+}
+
+impl<T> PokemonEvolutions<T>
+where
+    T: EvolutionRetrieval,
+{
+    pub fn new(evolution_retrieval: T) -> Self {
+        Self {
+            evolution_retrieval,
+        }
+    }
+    #[tracing::instrument(
+        name = "Getting pokemon evolutions",
+        skip(self, pokemon_name),
+        fields(pokemon_name = %pokemon_name)
+    )]
+    pub async fn get(&self, pokemon_name: String) -> Result<EvolutionChain, PokemonError> {
+        let pokemon_name = normalize_name(&pokemon_name)?;
+        self.evolution_retrieval.get_evolutions(pokemon_name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use crate::pokemon_bounded_context::domain::{EvolutionChain, EvolutionSpecies, PokemonError};
+    use crate::pokemon_bounded_context::port::out::MockEvolutionRetrieval;
+    use crate::pokemon_bounded_context::port::service::pokemon_evolutions::PokemonEvolutions;
+
+    #[tokio::test]
+    async fn pokemon_evolutions_service_calls_the_correct_port() {
+        let mut evolution_retrieval_port = MockEvolutionRetrieval::new();
+        evolution_retrieval_port
+            .expect_get_evolutions()
+            .with(eq("eevee".to_string()))
+            .times(1)
+            .returning(|_| {
+                EvolutionChain::from_species(vec![EvolutionSpecies::new(
+                    133,
+                    "eevee".to_string(),
+                    None,
+                    vec![],
+                )])
+            });
+        let pokemon_evolutions_service = PokemonEvolutions::new(evolution_retrieval_port);
+        pokemon_evolutions_service
+            .get("eevee".into())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn pokemon_evolutions_service_rejects_invalid_names_without_calling_the_port() {
+        let mut evolution_retrieval_port = MockEvolutionRetrieval::new();
+        evolution_retrieval_port.expect_get_evolutions().times(0);
+        let pokemon_evolutions_service = PokemonEvolutions::new(evolution_retrieval_port);
+        assert!(matches!(
+            pokemon_evolutions_service.get("pika/chu".into()).await,
+            Err(PokemonError::InvalidName(_))
+        ));
+    }
+}
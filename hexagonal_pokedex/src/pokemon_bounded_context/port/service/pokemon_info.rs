@@ -1,57 +1,363 @@
-use anyhow::Context;
+use std::sync::Arc;
 
-use crate::pokemon_bounded_context::domain::Pokemon;
-use crate::pokemon_bounded_context::port::out::PokemonRetrieval;
+use crate::pokemon_bounded_context::domain::{
+    fallback_chain, normalize_name, Language, Pokemon, PokemonError, PokemonLookup,
+};
+use crate::pokemon_bounded_context::port::out::{PokemonRetrieval, SpeciesRetrieval};
+use crate::pokemon_bounded_context::port::service::single_flight::SingleFlight;
+use crate::pokemon_bounded_context::port::service::SpeciesNames;
 
-pub struct PokemonInfo<T>
+pub struct PokemonInfo<T, S>
 where
     T: PokemonRetrieval,
+    S: SpeciesRetrieval,
 {
     pokemon_retrieval: T,
+    species_names: SpeciesNames<S>,
+    fallback_languages: Vec<Language>,
+    max_batch_size: usize,
+    in_flight: SingleFlight<(PokemonLookup, Vec<Language>), Result<Pokemon, Arc<PokemonError>>>,
 }
 
-impl<T> PokemonInfo<T>
+impl<T, S> PokemonInfo<T, S>
 where
     T: PokemonRetrieval,
+    S: SpeciesRetrieval,
 {
-    pub fn new(pokemon_retrieval: T) -> Self {
-        Self { pokemon_retrieval }
+    pub fn new(
+        pokemon_retrieval: T,
+        species_names: SpeciesNames<S>,
+        fallback_languages: Vec<Language>,
+        max_batch_size: usize,
+    ) -> Self {
+        Self {
+            pokemon_retrieval,
+            species_names,
+            fallback_languages,
+            max_batch_size,
+            in_flight: SingleFlight::new(),
+        }
     }
-    pub async fn get(&self, pokemon_name: String) -> anyhow::Result<Pokemon> {
-        self.pokemon_retrieval
-            .get(pokemon_name)
+    /// Retrieve the pokemon with the description in the best match for `languages`,
+    /// falling back to the parent languages and then to the configured fallback languages.
+    ///
+    /// Concurrent retrievals of the same pokemon in the same languages share a single port call.
+    /// A pokemon unknown by name is reported with the known names closest to the requested one.
+    #[tracing::instrument(
+        name = "Getting pokemon info",
+        skip(self, lookup, languages),
+        fields(pokemon_name = %lookup)
+    )]
+    pub async fn get(
+        &self,
+        lookup: PokemonLookup,
+        languages: &[Language],
+    ) -> Result<Pokemon, PokemonError> {
+        let languages = fallback_chain(languages, &self.fallback_languages);
+        let pokemon = self
+            .in_flight
+            .run((lookup.clone(), languages.clone()), async {
+                self.pokemon_retrieval
+                    .get(lookup.clone(), languages)
+                    .await
+                    .map_err(Arc::new)
+            })
             .await
-            .with_context(|| "Failed to get pokemon info")
+            .map_err(PokemonError::from);
+        match (pokemon, lookup) {
+            (Err(PokemonError::NotFound), PokemonLookup::ByName(pokemon_name)) => {
+                let similar_names = self.species_names.closest(&pokemon_name).await;
+                if similar_names.is_empty() {
+                    Err(PokemonError::NotFound)
+                } else {
+                    Err(PokemonError::NotFoundSimilarTo(similar_names))
+                }
+            }
+            (pokemon, _) => pokemon,
+        }
+    }
+    /// Retrieve many pokemon with a single port call, reporting the outcome of each distinct name.
+    ///
+    /// An invalid or unknown name only fails its own entry,
+    /// while a failure of the port fails the whole batch.
+    #[tracing::instrument(
+        name = "Getting many pokemon info",
+        skip(self, pokemon_names, languages),
+        fields(pokemon_count = pokemon_names.len())
+    )]
+    pub async fn get_many(
+        &self,
+        pokemon_names: Vec<String>,
+        languages: &[Language],
+    ) -> Result<Vec<(String, Result<Pokemon, PokemonError>)>, PokemonError> {
+        let mut distinct_names: Vec<String> = Vec::with_capacity(pokemon_names.len());
+        for pokemon_name in pokemon_names {
+            if !distinct_names.contains(&pokemon_name) {
+                distinct_names.push(pokemon_name);
+            }
+        }
+        if distinct_names.len() > self.max_batch_size {
+            return Err(PokemonError::BatchTooLarge(self.max_batch_size));
+        }
+
+        let normalized_names: Vec<Result<String, PokemonError>> = distinct_names
+            .iter()
+            .map(|pokemon_name| normalize_name(pokemon_name))
+            .collect();
+        let mut valid_names: Vec<String> = Vec::with_capacity(normalized_names.len());
+        for pokemon_name in normalized_names.iter().flatten() {
+            if !valid_names.contains(pokemon_name) {
+                valid_names.push(pokemon_name.clone());
+            }
+        }
+        let found = if valid_names.is_empty() {
+            Vec::new()
+        } else {
+            self.pokemon_retrieval
+                .get_many(
+                    valid_names,
+                    fallback_chain(languages, &self.fallback_languages),
+                )
+                .await?
+        };
+
+        Ok(distinct_names
+            .into_iter()
+            .zip(normalized_names)
+            .map(|(pokemon_name, normalized_name)| {
+                let pokemon = normalized_name.and_then(|normalized_name| {
+                    found
+                        .iter()
+                        .find(|pokemon| pokemon.name() == normalized_name)
+                        .cloned()
+                        .ok_or(PokemonError::NotFound)
+                });
+                (pokemon_name, pokemon)
+            })
+            .collect())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use mockall::predicate::eq;
 
-    use crate::pokemon_bounded_context::domain::Pokemon;
-    use crate::pokemon_bounded_context::port::out::MockPokemonRetrieval;
+    use crate::pokemon_bounded_context::domain::{Language, Pokemon, PokemonError, PokemonLookup};
+    use crate::pokemon_bounded_context::port::out::{MockPokemonRetrieval, MockSpeciesRetrieval};
     use crate::pokemon_bounded_context::port::service::pokemon_info::PokemonInfo;
+    use crate::pokemon_bounded_context::port::service::SpeciesNames;
 
     #[tokio::test]
     async fn retrieve_pokemon_service_calls_the_correct_port() {
         let mut retrieve_pokemon_port = MockPokemonRetrieval::new();
         retrieve_pokemon_port
             .expect_get()
-            .with(eq("any_pokemon_name".to_string()))
+            .with(
+                eq(by_name("any-pokemon-name")),
+                eq(vec![Language::new("en")]),
+            )
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Ok(Pokemon::new(
                     None,
                     None,
                     false,
-                    "any_pokemon_name".to_string(),
+                    None,
+                    "any-pokemon-name".to_string(),
+                ))
+            });
+        let retrieve_pokemon_service = PokemonInfo::new(
+            retrieve_pokemon_port,
+            species_names(),
+            vec![Language::new("en")],
+            10,
+        );
+        retrieve_pokemon_service
+            .get(by_name("any-pokemon-name"), &[])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn retrieve_pokemon_service_requests_the_language_fallback_chain() {
+        let mut retrieve_pokemon_port = MockPokemonRetrieval::new();
+        retrieve_pokemon_port
+            .expect_get()
+            .with(
+                eq(by_name("any-pokemon-name")),
+                eq(vec![
+                    Language::new("fr-CA"),
+                    Language::new("fr"),
+                    Language::new("en"),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(Pokemon::new(
+                    Some("any_description".to_string()),
+                    None,
+                    false,
+                    Some(Language::new("fr")),
+                    "any-pokemon-name".to_string(),
                 ))
             });
-        let retrieve_pokemon_service = PokemonInfo::new(retrieve_pokemon_port);
+        let retrieve_pokemon_service = PokemonInfo::new(
+            retrieve_pokemon_port,
+            species_names(),
+            vec![Language::new("en")],
+            10,
+        );
+        let pokemon = retrieve_pokemon_service
+            .get(by_name("any-pokemon-name"), &[Language::new("fr-CA")])
+            .await
+            .unwrap();
+        assert_eq!(&Some(Language::new("fr")), pokemon.language());
+    }
+
+    #[tokio::test]
+    async fn retrieve_pokemon_service_suggests_no_name_for_an_unknown_id() {
+        let mut retrieve_pokemon_port = MockPokemonRetrieval::new();
+        retrieve_pokemon_port
+            .expect_get()
+            .returning(|_, _| Err(PokemonError::NotFound));
+        let retrieve_pokemon_service = PokemonInfo::new(
+            retrieve_pokemon_port,
+            species_names(),
+            vec![Language::new("en")],
+            10,
+        );
+        assert!(matches!(
+            retrieve_pokemon_service
+                .get(PokemonLookup::ById(9999), &[])
+                .await,
+            Err(PokemonError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn retrieve_many_pokemon_service_reports_each_name_with_a_single_port_call() {
+        let mut retrieve_pokemon_port = MockPokemonRetrieval::new();
+        retrieve_pokemon_port
+            .expect_get_many()
+            .with(
+                eq(vec!["mewtwo".to_string(), "missingno".to_string()]),
+                eq(vec![Language::new("en")]),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![Pokemon::new(
+                    None,
+                    None,
+                    true,
+                    None,
+                    "mewtwo".to_string(),
+                )])
+            });
+        let retrieve_pokemon_service = PokemonInfo::new(
+            retrieve_pokemon_port,
+            species_names(),
+            vec![Language::new("en")],
+            10,
+        );
+        let results = retrieve_pokemon_service
+            .get_many(
+                vec![
+                    "mewtwo".to_string(),
+                    "pika/chu".to_string(),
+                    "missingno".to_string(),
+                    "mewtwo".to_string(),
+                ],
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(3, results.len());
+        assert!(
+            matches!(&results[0], (name, Ok(pokemon)) if name == "mewtwo" && pokemon.name() == "mewtwo")
+        );
+        assert!(
+            matches!(&results[1], (name, Err(PokemonError::InvalidName(_))) if name == "pika/chu")
+        );
+        assert!(matches!(&results[2], (name, Err(PokemonError::NotFound)) if name == "missingno"));
+    }
+
+    #[tokio::test]
+    async fn retrieve_many_pokemon_service_rejects_batches_too_large() {
+        let mut retrieve_pokemon_port = MockPokemonRetrieval::new();
+        retrieve_pokemon_port.expect_get_many().times(0);
+        let retrieve_pokemon_service = PokemonInfo::new(
+            retrieve_pokemon_port,
+            species_names(),
+            vec![Language::new("en")],
+            1,
+        );
+        assert!(matches!(
+            retrieve_pokemon_service
+                .get_many(vec!["mewtwo".to_string(), "pikachu".to_string()], &[])
+                .await,
+            Err(PokemonError::BatchTooLarge(1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn retrieve_pokemon_service_looks_up_the_pokemon_by_id() {
+        let mut retrieve_pokemon_port = MockPokemonRetrieval::new();
+        retrieve_pokemon_port
+            .expect_get()
+            .with(eq(PokemonLookup::ById(122)), eq(vec![Language::new("en")]))
+            .times(1)
+            .returning(|_, _| {
+                Ok(Pokemon::new(None, None, false, None, "mr-mime".to_string()).with_id(122))
+            });
+        let retrieve_pokemon_service = PokemonInfo::new(
+            retrieve_pokemon_port,
+            species_names(),
+            vec![Language::new("en")],
+            10,
+        );
         retrieve_pokemon_service
-            .get("any_pokemon_name".into())
+            .get(PokemonLookup::ById(122), &[])
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn retrieve_pokemon_service_suggests_the_closest_names_of_an_unknown_pokemon() {
+        let mut retrieve_pokemon_port = MockPokemonRetrieval::new();
+        retrieve_pokemon_port
+            .expect_get()
+            .returning(|_, _| Err(PokemonError::NotFound));
+        let retrieve_pokemon_service = PokemonInfo::new(
+            retrieve_pokemon_port,
+            species_names(),
+            vec![Language::new("en")],
+            10,
+        );
+
+        assert!(matches!(
+            retrieve_pokemon_service.get(by_name("pikachuu"), &[]).await,
+            Err(PokemonError::NotFoundSimilarTo(names)) if names == vec!["pikachu".to_string()]
+        ));
+        assert!(matches!(
+            retrieve_pokemon_service
+                .get(by_name("missingno"), &[])
+                .await,
+            Err(PokemonError::NotFound)
+        ));
+    }
+
+    fn by_name(pokemon_name: &str) -> PokemonLookup {
+        PokemonLookup::ByName(pokemon_name.to_string())
+    }
+
+    fn species_names() -> SpeciesNames<MockSpeciesRetrieval> {
+        let mut species_retrieval_port = MockSpeciesRetrieval::new();
+        species_retrieval_port
+            .expect_get_names()
+            .returning(|| Ok(vec!["pikachu".to_string(), "mewtwo".to_string()]));
+        SpeciesNames::new(species_retrieval_port, Duration::from_secs(3600), 3)
+    }
 }
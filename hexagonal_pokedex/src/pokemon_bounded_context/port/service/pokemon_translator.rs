@@ -1,106 +1,181 @@
-use crate::pokemon_bounded_context::domain::Pokemon;
-use crate::pokemon_bounded_context::port::out::{
-    CacheRetrieval, CacheUpdater, ShakespeareTranslator, YodaTranslator,
+use crate::pokemon_bounded_context::domain::{
+    CacheKey, Pokemon, PokemonError, RuleMatch, Translation, TranslationRules, TranslationSource,
+    TranslationStyle, UntranslatedReason,
 };
+use crate::pokemon_bounded_context::port::out::{CacheRetrieval, CacheUpdater, Translator};
+use crate::pokemon_bounded_context::port::service::single_flight::SingleFlight;
 
-pub struct PokemonTranslator<S, Y, R, U>
+pub struct PokemonTranslator<T, R, U>
 where
-    S: ShakespeareTranslator,
-    Y: YodaTranslator,
+    T: Translator,
     R: CacheRetrieval,
     U: CacheUpdater,
 {
-    shakespeare_translator: S,
-    yoda_translator: Y,
+    translator: T,
     cache_retrieval: R,
     cache_updater: U,
+    rules: TranslationRules,
+    rules_version: u32,
+    in_flight: SingleFlight<CacheKey, (String, Translation)>,
 }
 
-impl<S, Y, G, U> PokemonTranslator<S, Y, G, U>
+impl<T, G, U> PokemonTranslator<T, G, U>
 where
-    S: ShakespeareTranslator,
-    Y: YodaTranslator,
+    T: Translator,
     G: CacheRetrieval,
     U: CacheUpdater,
 {
+    /// Build the translator service.
+    ///
+    /// The `rules` select the translation style when none is requested.
+    /// The `rules_version` is part of every cache key:
+    /// bump it whenever the translation rules change to stop serving stale translations.
     pub fn new(
-        shakespeare_translator: S,
-        yoda_translator: Y,
+        translator: T,
         cache_retrieval: G,
         cache_updater: U,
+        rules: TranslationRules,
+        rules_version: u32,
     ) -> Self {
         PokemonTranslator {
-            shakespeare_translator,
-            yoda_translator,
+            translator,
             cache_retrieval,
             cache_updater,
+            rules,
+            rules_version,
+            in_flight: SingleFlight::new(),
         }
     }
-    pub async fn translate(&self, pokemon: Pokemon) -> anyhow::Result<Pokemon> {
+    /// Explain which rule selects the translation style of the pokemon.
+    pub fn explain(&self, pokemon: &Pokemon) -> RuleMatch {
+        self.rules.select(pokemon)
+    }
+    /// Check that the requested `style` is registered in the translator.
+    pub fn validate_style(&self, style: &TranslationStyle) -> Result<(), PokemonError> {
+        if self.translator.supports(style) {
+            Ok(())
+        } else {
+            Err(PokemonError::UnknownStyle(style.to_string()))
+        }
+    }
+    /// Translate the pokemon description in the requested `style`,
+    /// or in the one selected by the rules.
+    ///
+    /// When the translator fails or is rate limited, the original description is kept
+    /// and the returned pokemon reports why it has not been translated.
+    /// Cache failures are only logged, since the cache is an optimisation.
+    /// The local translations standing in for a failed remote one are not cached,
+    /// so that the next translation tries the remote translator again.
+    /// Concurrent translations with the same cache key share a single translation.
+    #[tracing::instrument(
+        name = "Translating pokemon description",
+        skip(self, pokemon, style),
+        fields(pokemon_name = %pokemon.name(), style)
+    )]
+    pub async fn translate(&self, pokemon: Pokemon, style: Option<&TranslationStyle>) -> Pokemon {
         match pokemon.description() {
-            None => Ok(pokemon),
+            None => pokemon
+                .with_translation(Translation::skipped(UntranslatedReason::MissingDescription)),
             Some(description) => {
-                let d = self
-                    .translate_description_and_update_cache(
-                        pokemon.name().to_string(),
-                        pokemon.is_cave_or_legendary(),
-                        description,
+                let style = match style {
+                    Some(style) => style.clone(),
+                    None => self.rules.select(&pokemon).style().clone(),
+                };
+                tracing::Span::current().record("style", &tracing::field::display(&style));
+                let cache_key = CacheKey::translation(
+                    pokemon.name().to_string(),
+                    style.clone(),
+                    pokemon.language().clone(),
+                    self.rules_version,
+                );
+                let (d, translation) = self
+                    .in_flight
+                    .run(
+                        cache_key.clone(),
+                        self.translate_description_and_update_cache(
+                            &cache_key,
+                            &style,
+                            description,
+                        ),
                     )
-                    .await?;
-                Ok(pokemon.with_description(d))
+                    .await;
+                pokemon.with_description(d).with_translation(translation)
             }
         }
     }
 
+    #[tracing::instrument(
+        name = "Translating the description through the cache",
+        skip(self, cache_key, style, description),
+        fields(cache)
+    )]
     async fn translate_description_and_update_cache(
         &self,
-        pokemon_name: String,
-        is_cave_or_legendary: bool,
+        cache_key: &CacheKey,
+        style: &TranslationStyle,
         description: &str,
-    ) -> anyhow::Result<String> {
-        match self.cache_retrieval.get(&pokemon_name).await {
-            Ok(Some(translated_description)) => Ok(translated_description),
-            _ => {
-                let translation = self
-                    .translate_without_cache(is_cave_or_legendary, description)
-                    .await?;
-                self.cache_updater
-                    .update(&pokemon_name, translation.clone())
-                    .await?;
-                Ok(translation)
+    ) -> (String, Translation) {
+        let span = tracing::Span::current();
+        match self.cache_retrieval.get(cache_key).await {
+            Ok(Some(translated_description)) => {
+                span.record("cache", &"hit");
+                return (
+                    translated_description,
+                    Translation::applied(self.translator.source(style)),
+                );
+            }
+            Ok(None) => {
+                span.record("cache", &"miss");
+            }
+            Err(e) => {
+                span.record("cache", &"error");
+                tracing::warn!("Failed to retrieve the cached translation.\n{:?}", e)
             }
         }
-    }
-
-    async fn translate_without_cache(
-        &self,
-        is_cave_or_legendary: bool,
-        description: &str,
-    ) -> anyhow::Result<String> {
-        if is_cave_or_legendary {
-            self.yoda_translator.to_yoda(description).await
-        } else {
-            self.shakespeare_translator
-                .to_shakespeare(description)
-                .await
+        let translation = match self.translator.translate(style, description).await {
+            Ok(translation) => translation,
+            Err(e) => {
+                tracing::warn!("Failed to translate the description.\n{:?}", e);
+                let reason = match e {
+                    PokemonError::RateLimited => UntranslatedReason::RateLimited,
+                    _ => UntranslatedReason::TranslatorError,
+                };
+                return (description.to_string(), Translation::skipped(reason));
+            }
+        };
+        let applied = Translation::applied(translation.source());
+        if translation.source() == TranslationSource::LocalFallback {
+            return (translation.into_text(), applied);
+        }
+        if let Err(e) = self
+            .cache_updater
+            .update(cache_key, translation.text().to_string())
+            .await
+        {
+            tracing::warn!("Failed to update the translations cache.\n{:?}", e);
         }
+        (translation.into_text(), applied)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use mockall::predicate::eq;
+    use serde_json::json;
 
-    use crate::pokemon_bounded_context::domain::Pokemon;
+    use crate::pokemon_bounded_context::domain::{
+        CacheKey, Language, Pokemon, PokemonError, RuleConditions, Translated, TranslationRule,
+        TranslationRules, TranslationSource, TranslationStyle,
+    };
     use crate::pokemon_bounded_context::port::out::MockCacheRetrieval;
     use crate::pokemon_bounded_context::port::out::MockCacheUpdater;
-    use crate::pokemon_bounded_context::port::out::MockShakespeareTranslator;
-    use crate::pokemon_bounded_context::port::out::MockYodaTranslator;
+    use crate::pokemon_bounded_context::port::out::MockTranslator;
     use crate::pokemon_bounded_context::port::service::pokemon_translator::PokemonTranslator;
 
     const POKEMON_DESCRIPTION: &str = "pokemon_description";
     const TRANSLATED_DESCRIPTION: &str = "translated_translation";
     const POKEMON_NAME: &str = "pokemon_name";
+    const RULES_VERSION: u32 = 1;
 
     #[tokio::test]
     async fn translate_pokemon_service_translates_to_yoda_and_cache_uncached_legendary_pokemon() {
@@ -108,40 +183,41 @@ mod tests {
             Some(POKEMON_DESCRIPTION.to_string()),
             None,
             true,
+            None,
             POKEMON_NAME.to_string(),
         );
 
-        let mut translate_to_shakespeare_port = MockShakespeareTranslator::new();
-        let mut translate_to_yoda_port = MockYodaTranslator::new();
+        let mut translator_port = MockTranslator::new();
         let mut get_cached_description_port = MockCacheRetrieval::new();
         let mut update_cached_description_port = MockCacheUpdater::new();
         given_yoda_translation(
-            &mut translate_to_shakespeare_port,
-            &mut translate_to_yoda_port,
+            &mut translator_port,
             POKEMON_DESCRIPTION,
             TRANSLATED_DESCRIPTION.to_string(),
         );
         given_cache_miss_and_update(
             &mut get_cached_description_port,
             &mut update_cached_description_port,
-            POKEMON_NAME,
+            cache_key(TranslationStyle::yoda(), None),
             TRANSLATED_DESCRIPTION.to_string(),
         );
 
         let translate_pokemon = PokemonTranslator::new(
-            translate_to_shakespeare_port,
-            translate_to_yoda_port,
+            translator_port,
             get_cached_description_port,
             update_cached_description_port,
+            rules(),
+            RULES_VERSION,
         );
-        let translated_pokemon = translate_pokemon
-            .translate(legendary_pokemon)
-            .await
-            .unwrap();
+        let translated_pokemon = translate_pokemon.translate(legendary_pokemon, None).await;
         assert_eq!(
             translated_pokemon.description().as_deref(),
             Some(TRANSLATED_DESCRIPTION)
-        )
+        );
+        assert_eq!(
+            json!({"applied": true, "source": "remote"}),
+            json!(translated_pokemon)["translation"]
+        );
     }
 
     #[tokio::test]
@@ -150,37 +226,41 @@ mod tests {
             Some(POKEMON_DESCRIPTION.to_string()),
             Some("cave".to_string()),
             false,
+            None,
             POKEMON_NAME.to_string(),
         );
 
-        let mut translate_to_shakespeare_port = MockShakespeareTranslator::new();
-        let mut translate_to_yoda_port = MockYodaTranslator::new();
+        let mut translator_port = MockTranslator::new();
         let mut get_cached_description_port = MockCacheRetrieval::new();
         let mut update_cached_description_port = MockCacheUpdater::new();
         given_yoda_translation(
-            &mut translate_to_shakespeare_port,
-            &mut translate_to_yoda_port,
+            &mut translator_port,
             POKEMON_DESCRIPTION,
             TRANSLATED_DESCRIPTION.to_string(),
         );
         given_cache_miss_and_update(
             &mut get_cached_description_port,
             &mut update_cached_description_port,
-            POKEMON_NAME,
+            cache_key(TranslationStyle::yoda(), None),
             TRANSLATED_DESCRIPTION.to_string(),
         );
 
         let translate_pokemon = PokemonTranslator::new(
-            translate_to_shakespeare_port,
-            translate_to_yoda_port,
+            translator_port,
             get_cached_description_port,
             update_cached_description_port,
+            rules(),
+            RULES_VERSION,
         );
-        let translated_pokemon = translate_pokemon.translate(cave_pokemon).await.unwrap();
+        let translated_pokemon = translate_pokemon.translate(cave_pokemon, None).await;
         assert_eq!(
             translated_pokemon.description().as_deref(),
             Some(TRANSLATED_DESCRIPTION)
-        )
+        );
+        assert_eq!(
+            json!({"applied": true, "source": "remote"}),
+            json!(translated_pokemon)["translation"]
+        );
     }
 
     #[tokio::test]
@@ -190,16 +270,15 @@ mod tests {
             Some(POKEMON_DESCRIPTION.to_string()),
             None,
             false,
+            None,
             POKEMON_NAME.to_string(),
         );
 
-        let mut translate_to_shakespeare_port = MockShakespeareTranslator::new();
-        let mut translate_to_yoda_port = MockYodaTranslator::new();
+        let mut translator_port = MockTranslator::new();
         let mut get_cached_description_port = MockCacheRetrieval::new();
         let mut update_cached_description_port = MockCacheUpdater::new();
         given_shakespeare_translation(
-            &mut translate_to_shakespeare_port,
-            &mut translate_to_yoda_port,
+            &mut translator_port,
             POKEMON_DESCRIPTION,
             TRANSLATED_DESCRIPTION.to_string(),
         );
@@ -207,24 +286,28 @@ mod tests {
         given_cache_miss_and_update(
             &mut get_cached_description_port,
             &mut update_cached_description_port,
-            POKEMON_NAME,
+            cache_key(TranslationStyle::shakespeare(), None),
             TRANSLATED_DESCRIPTION.to_string(),
         );
 
         let translate_pokemon = PokemonTranslator::new(
-            translate_to_shakespeare_port,
-            translate_to_yoda_port,
+            translator_port,
             get_cached_description_port,
             update_cached_description_port,
+            rules(),
+            RULES_VERSION,
         );
         let translated_pokemon = translate_pokemon
-            .translate(non_cave_non_legendary_pokemon)
-            .await
-            .unwrap();
+            .translate(non_cave_non_legendary_pokemon, None)
+            .await;
         assert_eq!(
             translated_pokemon.description().as_deref(),
             Some(TRANSLATED_DESCRIPTION)
-        )
+        );
+        assert_eq!(
+            json!({"applied": true, "source": "remote"}),
+            json!(translated_pokemon)["translation"]
+        );
     }
 
     #[tokio::test]
@@ -233,99 +316,419 @@ mod tests {
             Some(POKEMON_DESCRIPTION.to_string()),
             None,
             false,
+            Some(Language::new("fr")),
             POKEMON_NAME.to_string(),
         );
 
-        let mut translate_to_shakespeare_port = MockShakespeareTranslator::new();
-        let mut translate_to_yoda_port = MockYodaTranslator::new();
+        let mut translator_port = MockTranslator::new();
         let mut get_cached_description_port = MockCacheRetrieval::new();
         let mut update_cached_description_port = MockCacheUpdater::new();
         given_cache_hit(
-            &mut translate_to_shakespeare_port,
-            &mut translate_to_yoda_port,
+            &mut translator_port,
+            &mut get_cached_description_port,
+            &mut update_cached_description_port,
+            cache_key(TranslationStyle::shakespeare(), Some(Language::new("fr"))),
+            TRANSLATED_DESCRIPTION.to_string(),
+        );
+
+        let translate_pokemon = PokemonTranslator::new(
+            translator_port,
+            get_cached_description_port,
+            update_cached_description_port,
+            rules(),
+            RULES_VERSION,
+        );
+        let translated_pokemon = translate_pokemon.translate(cached_pokemon, None).await;
+        assert_eq!(
+            translated_pokemon.description().as_deref(),
+            Some(TRANSLATED_DESCRIPTION)
+        );
+        assert_eq!(
+            json!({"applied": true, "source": "remote"}),
+            json!(translated_pokemon)["translation"]
+        );
+    }
+
+    #[tokio::test]
+    async fn translate_pokemon_service_keeps_original_description_when_rate_limited() {
+        let legendary_pokemon = Pokemon::new(
+            Some(POKEMON_DESCRIPTION.to_string()),
+            None,
+            true,
+            None,
+            POKEMON_NAME.to_string(),
+        );
+
+        let mut translator_port = MockTranslator::new();
+        let mut get_cached_description_port = MockCacheRetrieval::new();
+        let mut update_cached_description_port = MockCacheUpdater::new();
+        translator_port
+            .expect_translate()
+            .with(eq(TranslationStyle::yoda()), eq(POKEMON_DESCRIPTION))
+            .times(1)
+            .returning(|_, _| Err(PokemonError::RateLimited));
+        given_cache_miss_without_update(
+            &mut get_cached_description_port,
+            &mut update_cached_description_port,
+            cache_key(TranslationStyle::yoda(), None),
+        );
+
+        let translate_pokemon = PokemonTranslator::new(
+            translator_port,
+            get_cached_description_port,
+            update_cached_description_port,
+            rules(),
+            RULES_VERSION,
+        );
+        let translated_pokemon = translate_pokemon.translate(legendary_pokemon, None).await;
+        assert_eq!(
+            translated_pokemon.description().as_deref(),
+            Some(POKEMON_DESCRIPTION)
+        );
+        assert_eq!(
+            json!({"applied": false, "reason": "rate_limited"}),
+            json!(translated_pokemon)["translation"]
+        );
+    }
+
+    #[tokio::test]
+    async fn translate_pokemon_service_does_not_cache_the_local_fallback_translation() {
+        let legendary_pokemon = Pokemon::new(
+            Some(POKEMON_DESCRIPTION.to_string()),
+            None,
+            true,
+            None,
+            POKEMON_NAME.to_string(),
+        );
+
+        let mut translator_port = MockTranslator::new();
+        let mut get_cached_description_port = MockCacheRetrieval::new();
+        let mut update_cached_description_port = MockCacheUpdater::new();
+        translator_port
+            .expect_translate()
+            .with(eq(TranslationStyle::yoda()), eq(POKEMON_DESCRIPTION))
+            .times(1)
+            .returning(|_, _| {
+                Ok(Translated::new(
+                    TRANSLATED_DESCRIPTION.to_string(),
+                    TranslationSource::LocalFallback,
+                ))
+            });
+        given_cache_miss_without_update(
+            &mut get_cached_description_port,
+            &mut update_cached_description_port,
+            cache_key(TranslationStyle::yoda(), None),
+        );
+
+        let translate_pokemon = PokemonTranslator::new(
+            translator_port,
+            get_cached_description_port,
+            update_cached_description_port,
+            rules(),
+            RULES_VERSION,
+        );
+        let translated_pokemon = translate_pokemon.translate(legendary_pokemon, None).await;
+        assert_eq!(
+            translated_pokemon.description().as_deref(),
+            Some(TRANSLATED_DESCRIPTION)
+        );
+        assert_eq!(
+            json!({"applied": true, "source": "local_fallback"}),
+            json!(translated_pokemon)["translation"]
+        );
+    }
+
+    #[tokio::test]
+    async fn translate_pokemon_service_keeps_original_description_when_translator_fails() {
+        let pokemon = Pokemon::new(
+            Some(POKEMON_DESCRIPTION.to_string()),
+            None,
+            false,
+            None,
+            POKEMON_NAME.to_string(),
+        );
+
+        let mut translator_port = MockTranslator::new();
+        let mut get_cached_description_port = MockCacheRetrieval::new();
+        let mut update_cached_description_port = MockCacheUpdater::new();
+        translator_port
+            .expect_translate()
+            .with(eq(TranslationStyle::shakespeare()), eq(POKEMON_DESCRIPTION))
+            .times(1)
+            .returning(|_, _| {
+                Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                    "translator_error"
+                )))
+            });
+        given_cache_miss_without_update(
             &mut get_cached_description_port,
             &mut update_cached_description_port,
-            POKEMON_NAME,
+            cache_key(TranslationStyle::shakespeare(), None),
+        );
+
+        let translate_pokemon = PokemonTranslator::new(
+            translator_port,
+            get_cached_description_port,
+            update_cached_description_port,
+            rules(),
+            RULES_VERSION,
+        );
+        let translated_pokemon = translate_pokemon.translate(pokemon, None).await;
+        assert_eq!(
+            translated_pokemon.description().as_deref(),
+            Some(POKEMON_DESCRIPTION)
+        );
+        assert_eq!(
+            json!({"applied": false, "reason": "translator_error"}),
+            json!(translated_pokemon)["translation"]
+        );
+    }
+
+    #[tokio::test]
+    async fn translate_pokemon_service_translates_despite_cache_errors() {
+        let pokemon = Pokemon::new(
+            Some(POKEMON_DESCRIPTION.to_string()),
+            None,
+            false,
+            None,
+            POKEMON_NAME.to_string(),
+        );
+
+        let mut translator_port = MockTranslator::new();
+        let mut get_cached_description_port = MockCacheRetrieval::new();
+        let mut update_cached_description_port = MockCacheUpdater::new();
+        given_shakespeare_translation(
+            &mut translator_port,
+            POKEMON_DESCRIPTION,
             TRANSLATED_DESCRIPTION.to_string(),
         );
+        get_cached_description_port
+            .expect_get()
+            .times(1)
+            .returning(|_| {
+                Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                    "cache_error"
+                )))
+            });
+        update_cached_description_port
+            .expect_update()
+            .times(1)
+            .returning(|_, _| {
+                Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                    "cache_error"
+                )))
+            });
 
         let translate_pokemon = PokemonTranslator::new(
-            translate_to_shakespeare_port,
-            translate_to_yoda_port,
+            translator_port,
             get_cached_description_port,
             update_cached_description_port,
+            rules(),
+            RULES_VERSION,
         );
-        let translated_pokemon = translate_pokemon.translate(cached_pokemon).await.unwrap();
+        let translated_pokemon = translate_pokemon.translate(pokemon, None).await;
         assert_eq!(
             translated_pokemon.description().as_deref(),
             Some(TRANSLATED_DESCRIPTION)
+        );
+    }
+
+    #[tokio::test]
+    async fn translate_pokemon_service_reports_missing_description() {
+        let pokemon = Pokemon::new(None, None, false, None, POKEMON_NAME.to_string());
+
+        let translate_pokemon = PokemonTranslator::new(
+            MockTranslator::new(),
+            MockCacheRetrieval::new(),
+            MockCacheUpdater::new(),
+            rules(),
+            RULES_VERSION,
+        );
+        let translated_pokemon = translate_pokemon.translate(pokemon, None).await;
+        assert_eq!(translated_pokemon.description(), &None);
+        assert_eq!(
+            json!({"applied": false, "reason": "missing_description"}),
+            json!(translated_pokemon)["translation"]
+        );
+    }
+
+    #[tokio::test]
+    async fn translate_pokemon_service_translates_to_the_requested_style() {
+        let legendary_pokemon = Pokemon::new(
+            Some(POKEMON_DESCRIPTION.to_string()),
+            None,
+            true,
+            None,
+            POKEMON_NAME.to_string(),
+        );
+        let pirate = TranslationStyle::new("pirate");
+
+        let mut translator_port = MockTranslator::new();
+        let mut get_cached_description_port = MockCacheRetrieval::new();
+        let mut update_cached_description_port = MockCacheUpdater::new();
+        given_translation(
+            &mut translator_port,
+            pirate.clone(),
+            POKEMON_DESCRIPTION,
+            TRANSLATED_DESCRIPTION.to_string(),
+        );
+        given_cache_miss_and_update(
+            &mut get_cached_description_port,
+            &mut update_cached_description_port,
+            cache_key(pirate.clone(), None),
+            TRANSLATED_DESCRIPTION.to_string(),
+        );
+
+        let translate_pokemon = PokemonTranslator::new(
+            translator_port,
+            get_cached_description_port,
+            update_cached_description_port,
+            rules(),
+            RULES_VERSION,
+        );
+        let translated_pokemon = translate_pokemon
+            .translate(legendary_pokemon, Some(&pirate))
+            .await;
+        assert_eq!(
+            translated_pokemon.description().as_deref(),
+            Some(TRANSLATED_DESCRIPTION)
+        );
+    }
+
+    #[test]
+    fn translate_pokemon_service_rejects_unknown_styles() {
+        let mut translator_port = MockTranslator::new();
+        translator_port
+            .expect_supports()
+            .returning(|style| style == &TranslationStyle::yoda());
+
+        let translate_pokemon = PokemonTranslator::new(
+            translator_port,
+            MockCacheRetrieval::new(),
+            MockCacheUpdater::new(),
+            rules(),
+            RULES_VERSION,
+        );
+        assert!(translate_pokemon
+            .validate_style(&TranslationStyle::yoda())
+            .is_ok());
+        assert!(matches!(
+            translate_pokemon.validate_style(&TranslationStyle::new("klingon")),
+            Err(PokemonError::UnknownStyle(_))
+        ));
+    }
+
+    fn rules() -> TranslationRules {
+        TranslationRules::new(
+            vec![
+                TranslationRule::new(
+                    "cave".to_string(),
+                    RuleConditions::new(Some("cave".to_string()), None, None, None, None),
+                    TranslationStyle::yoda(),
+                ),
+                TranslationRule::new(
+                    "legendary".to_string(),
+                    RuleConditions::new(None, Some(true), None, None, None),
+                    TranslationStyle::yoda(),
+                ),
+            ],
+            TranslationStyle::shakespeare(),
         )
     }
 
+    fn cache_key(style: TranslationStyle, language: Option<Language>) -> CacheKey {
+        CacheKey::translation(POKEMON_NAME.to_string(), style, language, RULES_VERSION)
+    }
+
     fn given_yoda_translation(
-        translate_to_shakespeare_port: &mut MockShakespeareTranslator,
-        translate_to_yoda_port: &mut MockYodaTranslator,
+        translator_port: &mut MockTranslator,
         pokemon_description: &'static str,
         translation: String,
     ) {
-        translate_to_shakespeare_port
-            .expect_to_shakespeare()
-            .times(0);
-        translate_to_yoda_port
-            .expect_to_yoda()
-            .with(eq(pokemon_description))
-            .times(1)
-            .returning(move |_| Ok(translation.clone()));
+        given_translation(
+            translator_port,
+            TranslationStyle::yoda(),
+            pokemon_description,
+            translation,
+        );
     }
 
     fn given_shakespeare_translation(
-        translate_to_shakespeare_port: &mut MockShakespeareTranslator,
-        translate_to_yoda_port: &mut MockYodaTranslator,
+        translator_port: &mut MockTranslator,
         pokemon_description: &'static str,
         translation: String,
     ) {
-        translate_to_shakespeare_port
-            .expect_to_shakespeare()
-            .with(eq(pokemon_description))
-            .times(1)
-            .returning(move |_| Ok(translation.clone()));
+        given_translation(
+            translator_port,
+            TranslationStyle::shakespeare(),
+            pokemon_description,
+            translation,
+        );
+    }
 
-        translate_to_yoda_port.expect_to_yoda().times(0);
+    fn given_translation(
+        translator_port: &mut MockTranslator,
+        style: TranslationStyle,
+        pokemon_description: &'static str,
+        translation: String,
+    ) {
+        translator_port
+            .expect_translate()
+            .with(eq(style), eq(pokemon_description))
+            .times(1)
+            .returning(move |_, _| {
+                Ok(Translated::new(
+                    translation.clone(),
+                    TranslationSource::Remote,
+                ))
+            });
     }
 
     fn given_cache_miss_and_update(
         get_cached_description_port: &mut MockCacheRetrieval,
         update_cached_description_port: &mut MockCacheUpdater,
-        pokemon_name: &'static str,
+        cache_key: CacheKey,
         translated_description: String,
     ) {
         get_cached_description_port
             .expect_get()
-            .with(eq(pokemon_name))
+            .with(eq(cache_key.clone()))
             .times(1)
             .returning(|_| Ok(None));
         update_cached_description_port
             .expect_update()
-            .with(eq(pokemon_name), eq(translated_description))
+            .with(eq(cache_key), eq(translated_description))
             .times(1)
             .returning(|_, _| Ok(()));
     }
 
+    fn given_cache_miss_without_update(
+        get_cached_description_port: &mut MockCacheRetrieval,
+        update_cached_description_port: &mut MockCacheUpdater,
+        cache_key: CacheKey,
+    ) {
+        get_cached_description_port
+            .expect_get()
+            .with(eq(cache_key))
+            .times(1)
+            .returning(|_| Ok(None));
+        update_cached_description_port.expect_update().times(0);
+    }
+
     fn given_cache_hit(
-        translate_to_shakespeare_port: &mut MockShakespeareTranslator,
-        translate_to_yoda_port: &mut MockYodaTranslator,
+        translator_port: &mut MockTranslator,
         get_cached_description_port: &mut MockCacheRetrieval,
         update_cached_description_port: &mut MockCacheUpdater,
-        pokemon_name: &'static str,
+        cache_key: CacheKey,
         translated_description: String,
     ) {
-        translate_to_shakespeare_port
-            .expect_to_shakespeare()
-            .times(0);
-        translate_to_yoda_port.expect_to_yoda().times(0);
+        translator_port.expect_translate().times(0);
+        translator_port
+            .expect_source()
+            .return_const(TranslationSource::Remote);
         get_cached_description_port
             .expect_get()
-            .with(eq(pokemon_name))
+            .with(eq(cache_key))
             .times(1)
             .returning(move |_| Ok(Some(translated_description.clone())));
         update_cached_description_port.expect_update().times(0);
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Mutex, MutexGuard};
+
+use futures::channel::oneshot;
+
+/// Coalesce the concurrent calls with the same key:
+/// the first caller runs its call, the others wait for its result.
+pub struct SingleFlight<K, V> {
+    in_flight: Mutex<HashMap<K, Vec<oneshot::Sender<V>>>>,
+}
+
+/// Forget the call of the first caller, should it be dropped before completing:
+/// its waiters then run their own calls.
+struct Flight<'a, K, V>
+where
+    K: Eq + Hash,
+{
+    single_flight: &'a SingleFlight<K, V>,
+    key: Option<K>,
+}
+
+impl<K, V> SingleFlight<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Run `call`, unless a call with the same `key` is in flight: then share its result.
+    pub async fn run(&self, key: K, call: impl Future<Output = V>) -> V {
+        let waiting = {
+            let mut in_flight = self.in_flight();
+            match in_flight.get_mut(&key) {
+                Some(waiters) => {
+                    let (sender, receiver) = oneshot::channel();
+                    waiters.push(sender);
+                    Some(receiver)
+                }
+                None => {
+                    in_flight.insert(key.clone(), Vec::new());
+                    None
+                }
+            }
+        };
+        if let Some(receiver) = waiting {
+            return match receiver.await {
+                Ok(value) => value,
+                Err(oneshot::Canceled) => call.await,
+            };
+        }
+
+        let mut flight = Flight {
+            single_flight: self,
+            key: Some(key),
+        };
+        let value = call.await;
+        for waiter in flight.land() {
+            // the waiter may have been dropped meanwhile
+            let _ = waiter.send(value.clone());
+        }
+        value
+    }
+}
+
+impl<K, V> SingleFlight<K, V>
+where
+    K: Eq + Hash,
+{
+    fn in_flight(&self) -> MutexGuard<'_, HashMap<K, Vec<oneshot::Sender<V>>>> {
+        self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<K, V> Default for SingleFlight<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Flight<'_, K, V>
+where
+    K: Eq + Hash,
+{
+    /// Remove the completed call, returning its waiters.
+    fn land(&mut self) -> Vec<oneshot::Sender<V>> {
+        self.key
+            .take()
+            .and_then(|key| self.single_flight.in_flight().remove(&key))
+            .unwrap_or_default()
+    }
+}
+
+impl<K, V> Drop for Flight<'_, K, V>
+where
+    K: Eq + Hash,
+{
+    fn drop(&mut self) {
+        // dropping the senders wakes up the waiters
+        self.land();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use futures::FutureExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn single_flight_shares_the_result_of_concurrent_calls() {
+        let single_flight = SingleFlight::new();
+        let calls = AtomicUsize::new(0);
+        let call = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            "value".to_string()
+        };
+
+        let (first, second) = futures::join!(
+            single_flight.run("key", call()),
+            single_flight.run("key", call())
+        );
+        assert_eq!("value", first);
+        assert_eq!("value", second);
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn single_flight_runs_calls_with_different_keys() {
+        let single_flight = SingleFlight::new();
+        let calls = AtomicUsize::new(0);
+        let call = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+
+        futures::join!(
+            single_flight.run("key", call()),
+            single_flight.run("other_key", call())
+        );
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn single_flight_runs_the_waiting_call_when_the_first_one_is_dropped() {
+        let single_flight = SingleFlight::new();
+
+        let mut first = single_flight
+            .run("key", futures::future::pending::<&str>())
+            .boxed_local();
+        assert!((&mut first).now_or_never().is_none());
+        let mut second = single_flight.run("key", async { "value" }).boxed_local();
+        assert!((&mut second).now_or_never().is_none());
+        drop(first);
+
+        assert_eq!("value", second.await);
+    }
+}
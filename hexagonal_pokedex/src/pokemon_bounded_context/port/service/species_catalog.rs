@@ -0,0 +1,195 @@
+use crate::pokemon_bounded_context::domain::{
+    PageRequest, PokemonError, SpeciesFilter, SpeciesPage,
+};
+use crate::pokemon_bounded_context::port::out::SpeciesListing;
+
+/// The largest offset or cursor, as PokeAPI takes them as signed 32 bits integers.
+const MAX_POSITION: u32 = i32::MAX as u32;
+
+pub struct SpeciesCatalog<T>
+where
+    T: SpeciesListing,
+{
+    species_listing: T,
+    default_limit: u32,
+    max_limit: u32,
+}
+
+impl<T> SpeciesCatalog<T>
+where
+    T: SpeciesListing,
+{
+    pub fn new(species_listing: T, default_limit: u32, max_limit: u32) -> Self {
+        Self {
+            species_listing,
+            default_limit,
+            max_limit,
+        }
+    }
+    /// The page requested by a client, with the default limit when it sets none.
+    ///
+    /// A cursor is the id of the species after which the page starts.
+    pub fn page_request(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<PageRequest, PokemonError> {
+        let after_id = cursor
+            .map(|cursor| {
+                cursor
+                    .parse()
+                    .ok()
+                    .filter(|after_id| *after_id <= MAX_POSITION)
+                    .ok_or_else(|| PokemonError::InvalidPage(format!("unknown cursor {}", cursor)))
+            })
+            .transpose()?;
+        let offset = offset.unwrap_or(0);
+        if offset > MAX_POSITION {
+            return Err(PokemonError::InvalidPage(format!(
+                "the offset must be at most {}",
+                MAX_POSITION
+            )));
+        }
+        Ok(PageRequest::new(
+            limit.unwrap_or(self.default_limit),
+            offset,
+            after_id,
+        ))
+    }
+    /// List a page of the species matching `filter`.
+    ///
+    /// The cursor of the next page is the id of the last listed species:
+    /// a single species more than the page is retrieved to know whether there is a next page.
+    pub async fn list(
+        &self,
+        filter: SpeciesFilter,
+        page: PageRequest,
+    ) -> Result<SpeciesPage, PokemonError> {
+        if page.limit() == 0 || page.limit() > self.max_limit {
+            return Err(PokemonError::InvalidPage(format!(
+                "the limit must be between 1 and {}",
+                self.max_limit
+            )));
+        }
+        let (mut species, total) = self
+            .species_listing
+            .list(filter, page.with_limit(page.limit() + 1))
+            .await?;
+
+        let has_next_page = species.len() > page.limit() as usize;
+        species.truncate(page.limit() as usize);
+        let next_cursor = species
+            .last()
+            .filter(|_| has_next_page)
+            .map(|last| last.id().to_string());
+        Ok(SpeciesPage::new(total, species, next_cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use serde_json::json;
+
+    use crate::pokemon_bounded_context::domain::SpeciesSummary;
+    use crate::pokemon_bounded_context::port::out::MockSpeciesListing;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn species_catalog_returns_the_cursor_of_the_next_page() {
+        let mut species_listing_port = MockSpeciesListing::new();
+        species_listing_port
+            .expect_list()
+            .with(
+                eq(SpeciesFilter::default()),
+                eq(PageRequest::new(3, 0, Some(1))),
+            )
+            .times(1)
+            .returning(|_, _| Ok((species(&[2, 3, 4]), 10)));
+
+        let catalog = SpeciesCatalog::new(species_listing_port, 10, 20);
+        let page = catalog
+            .list(SpeciesFilter::default(), PageRequest::new(2, 0, Some(1)))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            json!({
+                "total": 10,
+                "results": [{ "id": 2, "name": "species-2" }, { "id": 3, "name": "species-3" }],
+                "nextCursor": "3"
+            }),
+            json!(page)
+        );
+    }
+
+    #[tokio::test]
+    async fn species_catalog_returns_no_cursor_on_the_last_page() {
+        let mut species_listing_port = MockSpeciesListing::new();
+        species_listing_port
+            .expect_list()
+            .returning(|_, _| Ok((species(&[9, 10]), 10)));
+
+        let catalog = SpeciesCatalog::new(species_listing_port, 10, 20);
+        let page = catalog
+            .list(SpeciesFilter::default(), PageRequest::new(2, 8, None))
+            .await
+            .unwrap();
+
+        assert_eq!(None, page.next_cursor());
+    }
+
+    #[tokio::test]
+    async fn species_catalog_rejects_limits_out_of_bounds() {
+        let mut species_listing_port = MockSpeciesListing::new();
+        species_listing_port.expect_list().times(0);
+
+        let catalog = SpeciesCatalog::new(species_listing_port, 10, 20);
+        for limit in &[0, 21] {
+            assert!(matches!(
+                catalog
+                    .list(SpeciesFilter::default(), PageRequest::new(*limit, 0, None))
+                    .await,
+                Err(PokemonError::InvalidPage(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn species_catalog_resumes_the_page_after_the_cursor() {
+        let catalog = SpeciesCatalog::new(MockSpeciesListing::new(), 10, 20);
+        assert_eq!(
+            PageRequest::new(10, 0, Some(25)),
+            catalog.page_request(None, None, Some("25")).unwrap()
+        );
+        assert!(matches!(
+            catalog.page_request(Some(5), None, Some("pikachu")),
+            Err(PokemonError::InvalidPage(_))
+        ));
+    }
+
+    #[test]
+    fn species_catalog_rejects_positions_beyond_pokeapi_integers() {
+        let catalog = SpeciesCatalog::new(MockSpeciesListing::new(), 10, 20);
+        assert_eq!(
+            PageRequest::new(10, 2147483647, None),
+            catalog.page_request(None, Some(2147483647), None).unwrap()
+        );
+        assert!(matches!(
+            catalog.page_request(None, Some(2147483648), None),
+            Err(PokemonError::InvalidPage(_))
+        ));
+        assert!(matches!(
+            catalog.page_request(None, None, Some("2147483648")),
+            Err(PokemonError::InvalidPage(_))
+        ));
+    }
+
+    fn species(ids: &[u32]) -> Vec<SpeciesSummary> {
+        ids.iter()
+            .map(|id| SpeciesSummary::new(*id, format!("species-{}", id)))
+            .collect()
+    }
+}
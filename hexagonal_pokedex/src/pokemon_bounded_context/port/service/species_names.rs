@@ -0,0 +1,161 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::pokemon_bounded_context::domain::{closest_names, PokemonError};
+use crate::pokemon_bounded_context::port::out::SpeciesRetrieval;
+use crate::pokemon_bounded_context::port::service::single_flight::SingleFlight;
+
+/// Delay before loading the names again after a failure,
+/// so that the unknown pokemon do not add to the load of an unhealthy upstream service.
+const FAILURE_BACKOFF: Duration = Duration::from_secs(30);
+
+/// The names of the known species, used to suggest a name for the unknown ones.
+///
+/// They are loaded from the port on the first use, then reloaded once older than `refresh_interval`.
+/// A failed reload keeps the names loaded before, and no load is tried again for `FAILURE_BACKOFF`.
+pub struct SpeciesNames<S>
+where
+    S: SpeciesRetrieval,
+{
+    species_retrieval: S,
+    refresh_interval: Duration,
+    max_suggestions: usize,
+    loaded: Mutex<Option<Loaded>>,
+    failed_at: Mutex<Option<Instant>>,
+    loading: SingleFlight<(), Result<Arc<Vec<String>>, Arc<PokemonError>>>,
+}
+
+#[derive(Clone)]
+struct Loaded {
+    names: Arc<Vec<String>>,
+    at: Instant,
+}
+
+impl<S> SpeciesNames<S>
+where
+    S: SpeciesRetrieval,
+{
+    pub fn new(species_retrieval: S, refresh_interval: Duration, max_suggestions: usize) -> Self {
+        Self {
+            species_retrieval,
+            refresh_interval,
+            max_suggestions,
+            loaded: Mutex::new(None),
+            failed_at: Mutex::new(None),
+            loading: SingleFlight::new(),
+        }
+    }
+
+    /// The known names closest to the normalized `pokemon_name`,
+    /// none when the names cannot be loaded.
+    pub async fn closest(&self, pokemon_name: &str) -> Vec<String> {
+        match self.names().await {
+            Some(names) => closest_names(pokemon_name, &names, self.max_suggestions),
+            None => Vec::new(),
+        }
+    }
+
+    async fn names(&self) -> Option<Arc<Vec<String>>> {
+        let loaded = self.loaded().clone();
+        if let Some(loaded) = &loaded {
+            if loaded.at.elapsed() < self.refresh_interval {
+                return Some(loaded.names.clone());
+            }
+        }
+        if self
+            .failed_at()
+            .is_some_and(|at| at.elapsed() < FAILURE_BACKOFF)
+        {
+            return loaded.map(|loaded| loaded.names);
+        }
+        let reloaded = self
+            .loading
+            .run((), async {
+                let names = Arc::new(self.species_retrieval.get_names().await.map_err(Arc::new)?);
+                *self.loaded() = Some(Loaded {
+                    names: names.clone(),
+                    at: Instant::now(),
+                });
+                Ok(names)
+            })
+            .await;
+        match reloaded {
+            Ok(names) => {
+                *self.failed_at() = None;
+                Some(names)
+            }
+            Err(e) => {
+                tracing::warn!("Failed to load the species names.\n{:?}", e);
+                *self.failed_at() = Some(Instant::now());
+                loaded.map(|loaded| loaded.names)
+            }
+        }
+    }
+
+    fn loaded(&self) -> MutexGuard<'_, Option<Loaded>> {
+        self.loaded.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn failed_at(&self) -> MutexGuard<'_, Option<Instant>> {
+        self.failed_at.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pokemon_bounded_context::port::out::MockSpeciesRetrieval;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn species_names_are_loaded_once() {
+        let mut port = MockSpeciesRetrieval::new();
+        port.expect_get_names()
+            .times(1)
+            .returning(|| Ok(vec!["pikachu".to_string(), "pichu".to_string()]));
+
+        let species_names = SpeciesNames::new(port, Duration::from_secs(3600), 1);
+        assert_eq!(vec!["pikachu"], species_names.closest("pikachuu").await);
+        assert_eq!(vec!["pichu"], species_names.closest("pichuu").await);
+    }
+
+    #[tokio::test]
+    async fn species_names_are_reloaded_once_stale() {
+        let mut port = MockSpeciesRetrieval::new();
+        port.expect_get_names()
+            .times(2)
+            .returning(|| Ok(vec!["pikachu".to_string()]));
+
+        let species_names = SpeciesNames::new(port, Duration::from_secs(0), 3);
+        species_names.closest("pikachuu").await;
+        species_names.closest("pikachuu").await;
+    }
+
+    #[tokio::test]
+    async fn species_names_suggest_nothing_when_they_cannot_be_loaded() {
+        let mut port = MockSpeciesRetrieval::new();
+        port.expect_get_names().returning(|| {
+            Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                "any_error"
+            )))
+        });
+
+        let species_names = SpeciesNames::new(port, Duration::from_secs(3600), 3);
+        assert!(species_names.closest("pikachuu").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn species_names_are_not_reloaded_right_after_a_failure() {
+        let mut port = MockSpeciesRetrieval::new();
+        port.expect_get_names().times(1).returning(|| {
+            Err(PokemonError::UpstreamUnavailable(anyhow::anyhow!(
+                "any_error"
+            )))
+        });
+
+        let species_names = SpeciesNames::new(port, Duration::from_secs(3600), 3);
+        for _ in 0..3 {
+            assert!(species_names.closest("pikachuu").await.is_empty());
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use hexagonal_pokedex::{load_configuration, PokedexApp};
+
 use crate::api::helpers::spawn_app;
 
 #[actix_rt::test]
@@ -7,3 +9,16 @@ async fn health_check_works() {
     let response = client.get(&health_check_endpoint).send().await.unwrap();
     assert!(response.status().is_success());
 }
+
+#[actix_rt::test]
+async fn app_does_not_start_with_a_zero_redis_ttl() {
+    let mut config = load_configuration().unwrap();
+    config.application.port = 0;
+    config.redis_cache.ttl_seconds = 0;
+
+    let error = PokedexApp::new(config).await.err().unwrap();
+    assert_eq!(
+        "The redis cache `ttl_seconds` must be greater than zero",
+        error.to_string()
+    );
+}
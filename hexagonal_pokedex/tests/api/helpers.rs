@@ -2,12 +2,13 @@ use rand::distributions::Alphanumeric;
 use rand::Rng;
 use reqwest::Response;
 use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, BodyPartialJsonMatcher};
 use wiremock::MockServer;
 
-use hexagonal_pokedex::{load_configuration, setup_tracing, PokedexApp};
+use hexagonal_pokedex::{load_configuration, setup_tracing, PokedexApp, Settings};
 
 lazy_static::lazy_static! {
- static ref TRACING: () = setup_tracing("test".into(),"debug".into());
+ static ref TRACING: () = setup_tracing("test".into(), "debug".into(), None).unwrap();
 }
 
 pub struct TestApp {
@@ -17,6 +18,11 @@ pub struct TestApp {
 }
 
 pub async fn spawn_app() -> TestApp {
+    spawn_app_with(|_| {}).await
+}
+
+/// Spawn the app after applying `configure` to the test configuration.
+pub async fn spawn_app_with(configure: impl FnOnce(&mut Settings)) -> TestApp {
     lazy_static::initialize(&TRACING);
 
     let pokeapi_server = MockServer::start().await;
@@ -26,6 +32,9 @@ pub async fn spawn_app() -> TestApp {
     config.application.port = 0;
     config.poke_api.url = pokeapi_server.uri().parse().unwrap();
     config.funtranslation_api.url = translated_server.uri().parse().unwrap();
+    // the tests share the same Redis: they enable the pokemon cache when they need it
+    config.pokemon_cache.ttl_seconds = 0;
+    configure(&mut config);
 
     let app = PokedexApp::new(config).await.unwrap();
 
@@ -40,8 +49,10 @@ pub async fn spawn_app() -> TestApp {
 
 pub struct PokeApiResponseBuilder<'a> {
     habitat_name: &'a str,
+    id: u32,
     name: String,
     is_legendary: bool,
+    language: &'a str,
     without_pokemon: bool,
 }
 
@@ -49,8 +60,10 @@ impl<'a> PokeApiResponseBuilder<'a> {
     pub fn new() -> PokeApiResponseBuilder<'a> {
         Self {
             habitat_name: "any_habitat",
+            id: 1,
             name: random_pokemon_name(),
             is_legendary: true,
+            language: "en",
             without_pokemon: false,
         }
     }
@@ -58,6 +71,10 @@ impl<'a> PokeApiResponseBuilder<'a> {
         self.habitat_name = name;
         self
     }
+    pub fn with_id(&mut self, id: u32) -> &mut Self {
+        self.id = id;
+        self
+    }
     pub fn with_name(&mut self, name: String) -> &mut Self {
         self.name = name;
         self
@@ -66,6 +83,10 @@ impl<'a> PokeApiResponseBuilder<'a> {
         self.is_legendary = is_legendary;
         self
     }
+    pub fn with_language(&mut self, language: &'a str) -> &mut Self {
+        self.language = language;
+        self
+    }
     pub fn without_pokemon(&mut self) -> &mut Self {
         self.without_pokemon = true;
         self
@@ -85,16 +106,53 @@ impl<'a> PokeApiResponseBuilder<'a> {
                    "data":{
                       "info":[
                          {
+                            "id":self.id,
                             "name":self.name,
                             "habitat":{
                                "name":self.habitat_name
                             },
                             "descriptions":[
                                {
-                                  "flavor_text":"any_description"
+                                  "flavor_text":"any_description",
+                                  "language":{
+                                     "name":self.language
+                                  }
                                }
                             ],
-                            "is_legendary":self.is_legendary
+                            "is_legendary":self.is_legendary,
+                            "is_mythical":false,
+                            "generation":{
+                               "name":"generation-i"
+                            },
+                            "varieties":[
+                               {
+                                  "height":7,
+                                  "weight":69,
+                                  "types":[
+                                     {
+                                        "pokemon_type":{
+                                           "name":"grass"
+                                        }
+                                     }
+                                  ],
+                                  "stats":[
+                                     {
+                                        "base_stat":45,
+                                        "stat":{
+                                           "name":"hp"
+                                        }
+                                     }
+                                  ],
+                                  "abilities":[
+                                     {
+                                        "is_hidden":false,
+                                        "ability":{
+                                           "name":"overgrow"
+                                        }
+                                     }
+                                  ]
+                               }
+                            ]
                          }
                       ]
                    }
@@ -119,6 +177,11 @@ pub fn valid_translation_response() -> Value {
     )
 }
 
+/// Match the PokeAPI queries retrieving the pokemon, not the species names.
+pub fn pokemon_query() -> BodyPartialJsonMatcher {
+    body_partial_json(json!({ "operationName": "GqlPokemon" }))
+}
+
 pub async fn execute_get_request(endpoint: &str) -> Response {
     let client = reqwest::Client::new();
     client.get(endpoint).send().await.unwrap()
@@ -128,6 +191,6 @@ pub fn random_pokemon_name() -> String {
     rand::thread_rng()
         .sample_iter(&Alphanumeric)
         .take(5)
-        .map(char::from)
+        .map(|c| char::from(c).to_ascii_lowercase())
         .collect()
 }
@@ -0,0 +1,78 @@
+use wiremock::matchers::method;
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::api::helpers::{
+    execute_get_request, spawn_app, valid_translation_response, PokeApiResponseBuilder,
+};
+
+#[actix_rt::test]
+async fn metrics_report_the_requests_by_route_and_the_upstream_calls() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    for name in &["bulbasaur", "ivysaur"] {
+        let response = execute_get_request(&format!("{}/pokemon/{}", test_app.address, name)).await;
+        assert_eq!(200, response.status());
+    }
+
+    let response = execute_get_request(&format!("{}/metrics", test_app.address)).await;
+    assert_eq!(200, response.status());
+    assert!(response.headers()["Content-Type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/plain"));
+
+    let metrics = response.text().await.unwrap();
+    assert!(metrics.contains(
+        "pokedex_http_requests_total{method=\"GET\",route=\"/pokemon/{name}\",status=\"200\"} 2"
+    ));
+    assert!(metrics.contains(
+        "pokedex_http_request_duration_seconds_count{method=\"GET\",route=\"/pokemon/{name}\",status=\"200\"} 2"
+    ));
+    assert!(metrics
+        .contains("pokedex_upstream_request_duration_seconds_count{upstream=\"poke_api\"} 2"));
+    // the request to the metrics is still in flight
+    assert!(metrics.contains("pokedex_http_requests_in_flight 1"));
+}
+
+#[actix_rt::test]
+async fn metrics_report_the_remaining_funtranslations_quota() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(valid_translation_response()))
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let metrics = execute_get_request(&format!("{}/metrics", test_app.address))
+        .await
+        .text()
+        .await
+        .unwrap();
+    assert!(metrics.contains("pokedex_funtranslation_quota_remaining 5"));
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+
+    let metrics = execute_get_request(&format!("{}/metrics", test_app.address))
+        .await
+        .text()
+        .await
+        .unwrap();
+    assert!(metrics.contains("pokedex_funtranslation_quota_remaining 4"));
+}
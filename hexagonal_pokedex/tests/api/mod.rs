@@ -1,4 +1,10 @@
 mod health_check;
 mod helpers;
+mod metrics;
 mod pokemon;
+mod pokemon_batch;
+mod pokemon_evolutions;
+mod pokemon_list;
 mod pokemon_translated;
+mod pokemon_translation_rule;
+mod readiness;
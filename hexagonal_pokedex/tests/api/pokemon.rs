@@ -1,7 +1,13 @@
-use wiremock::matchers::method;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method};
 use wiremock::{Mock, ResponseTemplate};
 
-use crate::api::helpers::{execute_get_request, spawn_app, PokeApiResponseBuilder};
+use crate::api::helpers::{
+    execute_get_request, pokemon_query, random_pokemon_name, spawn_app, spawn_app_with,
+    PokeApiResponseBuilder,
+};
 
 #[actix_rt::test]
 async fn pokemon_returns_200_with_valid_input() {
@@ -17,11 +23,64 @@ async fn pokemon_returns_200_with_valid_input() {
     assert_eq!(200, response.status());
 }
 
+#[actix_rt::test]
+async fn pokemon_returns_types_stats_and_abilities() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+    let response = execute_get_request(&format!("{}/pokemon/any_pokemon", test_app.address)).await;
+    assert_eq!(200, response.status());
+
+    let pokemon = response.json::<Value>().await.unwrap();
+    assert_eq!(json!(["grass"]), pokemon["types"]);
+    assert_eq!(json!([{ "name": "hp", "baseStat": 45 }]), pokemon["stats"]);
+    assert_eq!(
+        json!([{ "name": "overgrow", "isHidden": false }]),
+        pokemon["abilities"]
+    );
+    assert_eq!(7, pokemon["height"]);
+    assert_eq!(69, pokemon["weight"]);
+    assert_eq!(false, pokemon["isMythical"]);
+    assert_eq!("generation-i", pokemon["generation"]);
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_only_the_requested_fields() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                PokeApiResponseBuilder::new()
+                    .with_name("any_pokemon".to_string())
+                    .finish(),
+            ),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+    let response = execute_get_request(&format!(
+        "{}/pokemon/any_pokemon?fields=name,types",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+    assert_eq!(
+        json!({ "name": "any_pokemon", "types": ["grass"] }),
+        response.json::<Value>().await.unwrap()
+    );
+}
+
 #[actix_rt::test]
 async fn pokemon_returns_404_with_non_existent_pokemon() {
     let test_app = spawn_app().await;
 
     Mock::given(method("POST"))
+        .and(pokemon_query())
         .respond_with(
             ResponseTemplate::new(200)
                 .set_body_json(PokeApiResponseBuilder::new().without_pokemon().finish()),
@@ -33,3 +92,469 @@ async fn pokemon_returns_404_with_non_existent_pokemon() {
     let response = execute_get_request(&format!("{}/pokemon/any_pokemon", test_app.address)).await;
     assert_eq!(404, response.status());
 }
+
+#[actix_rt::test]
+async fn pokemon_returns_description_in_the_negotiated_language() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "variables": { "languages": ["fr-CA", "fr", "de", "en"] }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(PokeApiResponseBuilder::new().with_language("fr").finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/pokemon/any_pokemon", test_app.address))
+        .header("Accept-Language", "fr-CA, de;q=0.5")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(200, response.status());
+    assert_eq!("fr", response.headers()["Content-Language"]);
+    assert_eq!("fr", response.json::<Value>().await.unwrap()["language"]);
+}
+
+#[actix_rt::test]
+async fn pokemon_lang_query_parameter_overrides_accept_language() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "variables": { "languages": ["ja-Hrkt", "ja", "en"] }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(PokeApiResponseBuilder::new().with_language("en").finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/pokemon/any_pokemon?lang=ja-Hrkt",
+            test_app.address
+        ))
+        .header("Accept-Language", "fr")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(200, response.status());
+    assert_eq!("en", response.json::<Value>().await.unwrap()["language"]);
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_400_with_invalid_name() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!("{}/pokemon/pika%2Fchu", test_app.address)).await;
+    assert_eq!(400, response.status());
+    assert_eq!(
+        "/problems/invalid-name",
+        response.json::<Value>().await.unwrap()["type"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_400_with_empty_language() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    for lang in &["", "-", "fr--CA"] {
+        let response = execute_get_request(&format!(
+            "{}/pokemon/any_pokemon?lang={}",
+            test_app.address, lang
+        ))
+        .await;
+        assert_eq!(400, response.status());
+        assert_eq!(
+            "/problems/invalid-language",
+            response.json::<Value>().await.unwrap()["type"]
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_502_problem_without_causes_when_pokeapi_fails() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!("{}/pokemon/any_pokemon", test_app.address)).await;
+    assert_eq!(502, response.status());
+    assert_eq!(
+        "application/problem+json",
+        response.headers()["Content-Type"]
+    );
+    let mut problem = response.json::<Value>().await.unwrap();
+    assert!(problem["requestId"].is_string());
+    problem.as_object_mut().unwrap().remove("requestId");
+    assert_eq!(
+        json!({
+            "type": "/problems/upstream-unavailable",
+            "title": "Upstream unavailable",
+            "status": 502,
+            "detail": "The upstream service is unavailable",
+            "instance": "/pokemon/any_pokemon"
+        }),
+        problem
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_429_when_pokeapi_rate_limits() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429))
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!("{}/pokemon/any_pokemon", test_app.address)).await;
+    assert_eq!(429, response.status());
+    assert_eq!(
+        "/problems/rate-limited",
+        response.json::<Value>().await.unwrap()["type"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_504_when_pokeapi_times_out() {
+    let test_app = spawn_app_with(|config| config.poke_api.timeout_seconds = 1).await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(PokeApiResponseBuilder::new().finish())
+                .set_delay(Duration::from_secs(3)),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!("{}/pokemon/any_pokemon", test_app.address)).await;
+    assert_eq!(504, response.status());
+    assert_eq!(
+        "/problems/upstream-timeout",
+        response.json::<Value>().await.unwrap()["type"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_coalesces_concurrent_requests_of_the_same_pokemon() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(PokeApiResponseBuilder::new().finish())
+                .set_delay(Duration::from_millis(500)),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let url = format!("{}/pokemon/any_pokemon", test_app.address);
+    let responses = futures::future::join_all((0..3).map(|_| execute_get_request(&url))).await;
+    for response in responses {
+        assert_eq!(200, response.status());
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_is_cached() {
+    let test_app = spawn_app_with(|config| config.pokemon_cache.ttl_seconds = 60).await;
+    let name = random_pokemon_name();
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                PokeApiResponseBuilder::new()
+                    .with_name(name.clone())
+                    .finish(),
+            ),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let url = format!("{}/pokemon/{}", test_app.address, name);
+    for _ in 0..2 {
+        let response = execute_get_request(&url).await;
+        assert_eq!(200, response.status());
+        assert_eq!(name, response.json::<Value>().await.unwrap()["name"]);
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_not_found_is_cached() {
+    let test_app = spawn_app_with(|config| config.pokemon_cache.ttl_seconds = 60).await;
+    Mock::given(method("POST"))
+        .and(pokemon_query())
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(PokeApiResponseBuilder::new().without_pokemon().finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let url = format!("{}/pokemon/{}", test_app.address, random_pokemon_name());
+    for _ in 0..2 {
+        assert_eq!(404, execute_get_request(&url).await.status());
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_cache_control_and_etag_headers() {
+    let test_app = spawn_app_with(|config| config.http_cache.pokemon_max_age_seconds = 60).await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let url = format!("{}/pokemon/any_pokemon", test_app.address);
+    let first = execute_get_request(&url).await;
+    let second = execute_get_request(&url).await;
+
+    assert_eq!("public, max-age=60", first.headers()["Cache-Control"]);
+    assert_eq!("Accept-Language", first.headers()["Vary"]);
+    let etag = first.headers()["ETag"].to_str().unwrap();
+    assert!(etag.starts_with('"') && etag.ends_with('"'));
+    assert_eq!(etag, second.headers()["ETag"]);
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_304_when_the_etag_matches() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+    let url = format!("{}/pokemon/any_pokemon", test_app.address);
+    let etag = execute_get_request(&url).await.headers()["ETag"].clone();
+
+    let response = reqwest::Client::new()
+        .get(&url)
+        .header(
+            "If-None-Match",
+            format!("\"other\", {}", etag.to_str().unwrap()),
+        )
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(304, response.status());
+    assert_eq!(etag, response.headers()["ETag"]);
+    assert_eq!("public, max-age=86400", response.headers()["Cache-Control"]);
+    assert!(response.text().await.unwrap().is_empty());
+}
+
+#[actix_rt::test]
+async fn pokemon_returns_200_when_the_etag_does_not_match() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/pokemon/any_pokemon", test_app.address))
+        .header("If-None-Match", "\"stale\"")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(200, response.status());
+    assert!(response.json::<Value>().await.is_ok());
+}
+
+#[actix_rt::test]
+async fn pokemon_normalizes_the_requested_name() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(
+            json!({ "variables": { "names": ["mr-mime"] } }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                PokeApiResponseBuilder::new()
+                    .with_name("mr-mime".to_string())
+                    .finish(),
+            ),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!("{}/pokemon/Mr.%20Mime", test_app.address)).await;
+
+    assert_eq!(200, response.status());
+    assert_eq!("mr-mime", response.json::<Value>().await.unwrap()["name"]);
+}
+
+#[actix_rt::test]
+async fn pokemon_suggests_the_closest_names_of_an_unknown_pokemon() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .and(pokemon_query())
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(PokeApiResponseBuilder::new().without_pokemon().finish()),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(
+            json!({ "operationName": "GqlSpeciesNames" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "species": [{ "name": "pikachu" }, { "name": "pichu" }, { "name": "mewtwo" }]
+            }
+        })))
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    for _ in 0..2 {
+        let response = execute_get_request(&format!("{}/pokemon/pikachuu", test_app.address)).await;
+        assert_eq!(404, response.status());
+
+        let problem = response.json::<Value>().await.unwrap();
+        assert_eq!("/problems/not-found", problem["type"]);
+        assert_eq!(json!(["pikachu", "pichu"]), problem["suggestions"]);
+        assert_eq!(
+            "Pokemon not found, did you mean: pikachu, pichu?",
+            problem["detail"]
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_is_looked_up_by_pokedex_number() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(
+            json!({ "variables": { "names": [], "ids": [25] } }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                PokeApiResponseBuilder::new()
+                    .with_id(25)
+                    .with_name("pikachu".to_string())
+                    .finish(),
+            ),
+        )
+        .expect(2)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    for url in &["pokemon/25", "pokemon/by-id/25"] {
+        let response = execute_get_request(&format!("{}/{}", test_app.address, url)).await;
+        assert_eq!(200, response.status());
+
+        let pokemon = response.json::<Value>().await.unwrap();
+        assert_eq!("pikachu", pokemon["name"]);
+        assert_eq!(25, pokemon["id"]);
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_by_id_returns_400_with_invalid_number() {
+    let test_app = spawn_app().await;
+
+    for id in &["pikachu", "0"] {
+        let response =
+            execute_get_request(&format!("{}/pokemon/by-id/{}", test_app.address, id)).await;
+        assert_eq!(400, response.status());
+        assert_eq!(
+            "/problems/invalid-id",
+            response.json::<Value>().await.unwrap()["type"]
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_by_id_rejects_numbers_beyond_pokeapi_ids_without_calling_it() {
+    let test_app = spawn_app_with(|config| {
+        config.circuit_breaker.failure_threshold = 1;
+    })
+    .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    for _ in 0..2 {
+        let response =
+            execute_get_request(&format!("{}/pokemon/by-id/2147483648", test_app.address)).await;
+        assert_eq!(400, response.status());
+        assert_eq!(
+            "/problems/invalid-id",
+            response.json::<Value>().await.unwrap()["type"]
+        );
+    }
+
+    let response = execute_get_request(&format!("{}/readiness", test_app.address)).await;
+    assert_eq!(
+        "closed",
+        response.json::<Value>().await.unwrap()["circuits"]["poke_api"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_propagates_the_trace_context_to_pokeapi() {
+    let test_app = spawn_app().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let trace_id = "4bf92f3577b34da6a3ce929d0e0e4736";
+    let response = reqwest::Client::new()
+        .get(format!("{}/pokemon/any_pokemon", test_app.address))
+        .header(
+            "traceparent",
+            format!("00-{}-00f067aa0ba902b7-01", trace_id),
+        )
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(200, response.status());
+
+    let requests = test_app.pokeapi_server.received_requests().await.unwrap();
+    let traceparent = requests[0].headers[&"traceparent".into()].as_str();
+    // same trace, with the span of the service as parent
+    assert!(traceparent.starts_with(&format!("00-{}-", trace_id)));
+    assert!(!traceparent.contains("00f067aa0ba902b7"));
+}
@@ -0,0 +1,172 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::api::helpers::{
+    execute_get_request, random_pokemon_name, spawn_app, spawn_app_with,
+    valid_translation_response, PokeApiResponseBuilder,
+};
+
+#[actix_rt::test]
+async fn pokemon_batch_fetches_all_names_with_a_single_query() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "variables": { "names": ["mewtwo", "pikachu", "missingno"] }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pokeapi_response(&["mewtwo", "pikachu"])),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/batch?names=mewtwo,pikachu,missingno,pika%2Fchu",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+
+    let results = response.json::<Value>().await.unwrap()["results"].clone();
+    assert_eq!("mewtwo", results[0]["pokemon"]["name"]);
+    assert_eq!("pikachu", results[1]["pokemon"]["name"]);
+    assert_eq!("missingno", results[2]["name"]);
+    assert_eq!(404, results[2]["error"]["status"]);
+    assert_eq!("pika/chu", results[3]["name"]);
+    assert_eq!("/problems/invalid-name", results[3]["error"]["type"]);
+}
+
+#[actix_rt::test]
+async fn pokemon_batch_post_translates_the_pokemon_when_requested() {
+    let test_app = spawn_app().await;
+    let names = [random_pokemon_name(), random_pokemon_name()];
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(pokeapi_response(&[names[0].as_str(), names[1].as_str()])),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(valid_translation_response()))
+        .expect(2)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/pokemon/batch", test_app.address))
+        .json(&json!({ "names": names, "translate": true }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(200, response.status());
+
+    let results = response.json::<Value>().await.unwrap()["results"].clone();
+    for (index, name) in names.iter().enumerate() {
+        assert_eq!(name.as_str(), results[index]["pokemon"]["name"]);
+        assert_eq!(
+            "any_text_translated",
+            results[index]["pokemon"]["description"]
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_batch_returns_400_when_too_many_names_are_requested() {
+    let test_app = spawn_app_with(|config| config.batch.max_size = 2).await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/batch?names=mewtwo,pikachu,eevee",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(400, response.status());
+    assert_eq!(
+        "/problems/batch-too-large",
+        response.json::<Value>().await.unwrap()["type"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_batch_returns_400_problem_with_malformed_query() {
+    let test_app = spawn_app().await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/batch?names=mewtwo&translate=maybe",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(400, response.status());
+    assert_eq!(
+        "application/problem+json",
+        response.headers()["Content-Type"]
+    );
+    assert_eq!(
+        "/problems/invalid-request",
+        response.json::<Value>().await.unwrap()["type"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_batch_post_returns_400_problem_with_malformed_body() {
+    let test_app = spawn_app().await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/pokemon/batch", test_app.address))
+        .header("Content-Type", "application/json")
+        .body("{\"names\": [\"mewtwo\"")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(400, response.status());
+    assert_eq!(
+        "application/problem+json",
+        response.headers()["Content-Type"]
+    );
+    assert_eq!(
+        "/problems/invalid-request",
+        response.json::<Value>().await.unwrap()["type"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_batch_returns_502_when_pokeapi_fails() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/batch?names=mewtwo,pikachu",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(502, response.status());
+}
+
+fn pokeapi_response(names: &[&str]) -> Value {
+    let info = names
+        .iter()
+        .map(|name| {
+            PokeApiResponseBuilder::new()
+                .with_name(name.to_string())
+                .finish()["data"]["info"][0]
+                .clone()
+        })
+        .collect::<Vec<Value>>();
+    json!({ "data": { "info": info } })
+}
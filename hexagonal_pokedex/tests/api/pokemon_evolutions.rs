@@ -0,0 +1,85 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::api::helpers::{execute_get_request, spawn_app};
+
+#[actix_rt::test]
+async fn pokemon_evolutions_returns_the_branching_chain() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(
+            json!({ "variables": { "name": "eevee" } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(
+            {
+                "data":{
+                    "species":[
+                        {
+                            "chain":{
+                                "members":[
+                                    evolution_member(133, "eevee", None, json!([])),
+                                    evolution_member(134, "vaporeon", Some(133), json!([{
+                                        "trigger": { "name": "use-item" },
+                                        "item": { "name": "water-stone" }
+                                    }])),
+                                    evolution_member(196, "espeon", Some(133), json!([{
+                                        "min_happiness": 160,
+                                        "time_of_day": "day",
+                                        "trigger": { "name": "level-up" }
+                                    }]))
+                                ]
+                            }
+                        }
+                    ]
+                }
+            }
+        )))
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response =
+        execute_get_request(&format!("{}/pokemon/eevee/evolutions", test_app.address)).await;
+    assert_eq!(200, response.status());
+
+    let chain = response.json::<Value>().await.unwrap();
+    assert_eq!("eevee", chain["name"]);
+    assert_eq!("vaporeon", chain["evolvesTo"][0]["name"]);
+    assert_eq!(
+        "water-stone",
+        chain["evolvesTo"][0]["conditions"][0]["item"]
+    );
+    assert_eq!("espeon", chain["evolvesTo"][1]["name"]);
+    assert_eq!(160, chain["evolvesTo"][1]["conditions"][0]["minHappiness"]);
+}
+
+#[actix_rt::test]
+async fn pokemon_evolutions_returns_404_with_non_existent_pokemon() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "data": { "species": [] } })),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/any_pokemon/evolutions",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(404, response.status());
+}
+
+fn evolution_member(id: i64, name: &str, evolves_from: Option<i64>, evolutions: Value) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "evolves_from_species_id": evolves_from,
+        "evolutions": evolutions
+    })
+}
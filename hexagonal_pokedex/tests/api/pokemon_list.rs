@@ -0,0 +1,164 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::api::helpers::{execute_get_request, spawn_app};
+
+#[actix_rt::test]
+async fn pokemon_list_returns_a_page_of_the_filtered_species() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "operationName": "GqlSpeciesPage",
+            "variables": {
+                "total_where": { "_and": [
+                    { "pokemon_v2_pokemonhabitat": { "name": { "_eq": "cave" } } },
+                    { "pokemon_v2_pokemons": { "pokemon_v2_pokemontypes": {
+                        "pokemon_v2_type": { "name": { "_eq": "poison" } }
+                    } } }
+                ] },
+                "limit": 3,
+                "offset": 0
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "species": [
+                    { "id": 41, "name": "zubat" },
+                    { "id": 42, "name": "golbat" },
+                    { "id": 169, "name": "crobat" }
+                ],
+                "total": { "aggregate": { "count": 5 } }
+            }
+        })))
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon?limit=2&habitat=cave&type=poison",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+
+    let link = response.headers()["Link"].to_str().unwrap().to_string();
+    assert!(link.contains("/pokemon?limit=2&habitat=cave&type=poison>; rel=\"first\""));
+    assert!(link.contains("/pokemon?limit=2&cursor=42&habitat=cave&type=poison>; rel=\"next\""));
+
+    assert_eq!(
+        json!({
+            "total": 5,
+            "results": [{ "id": 41, "name": "zubat" }, { "id": 42, "name": "golbat" }],
+            "nextCursor": "42"
+        }),
+        response.json::<Value>().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_list_resumes_after_the_cursor() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "operationName": "GqlSpeciesPage",
+            "variables": {
+                "where": { "_and": [
+                    { "is_legendary": { "_eq": true } },
+                    { "id": { "_gt": 150 } }
+                ] },
+                "total_where": { "_and": [{ "is_legendary": { "_eq": true } }] }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "species": [{ "id": 151, "name": "mew" }],
+                "total": { "aggregate": { "count": 4 } }
+            }
+        })))
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon?legendary=true&cursor=150",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+
+    let link = response.headers()["Link"].to_str().unwrap().to_string();
+    assert!(!link.contains("rel=\"next\""));
+
+    let page = response.json::<Value>().await.unwrap();
+    assert_eq!(4, page["total"]);
+    assert_eq!("mew", page["results"][0]["name"]);
+    assert!(page.get("nextCursor").is_none());
+}
+
+#[actix_rt::test]
+async fn pokemon_list_links_do_not_trust_the_forwarded_host() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "species": [{ "id": 1, "name": "bulbasaur" }, { "id": 2, "name": "ivysaur" }],
+                "total": { "aggregate": { "count": 3 } }
+            }
+        })))
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/pokemon?limit=1&q=saur", test_app.address))
+        .header("X-Forwarded-Host", "a b")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(200, response.status());
+
+    let link = response.headers()["Link"].to_str().unwrap().to_string();
+    assert_eq!(
+        "</pokemon?limit=1&q=saur>; rel=\"first\", </pokemon?limit=1&cursor=1&q=saur>; rel=\"next\"",
+        link
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_list_returns_400_with_invalid_page() {
+    let test_app = spawn_app().await;
+
+    for query in &[
+        "limit=0",
+        "limit=1000",
+        "cursor=pikachu",
+        "offset=3000000000",
+        "cursor=3000000000",
+    ] {
+        let response =
+            execute_get_request(&format!("{}/pokemon?{}", test_app.address, query)).await;
+        assert_eq!(400, response.status());
+        assert_eq!(
+            "/problems/invalid-page",
+            response.json::<Value>().await.unwrap()["type"]
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_list_returns_400_problem_with_malformed_query() {
+    let test_app = spawn_app().await;
+
+    let response = execute_get_request(&format!("{}/pokemon?limit=abc", test_app.address)).await;
+    assert_eq!(400, response.status());
+    assert_eq!(
+        "application/problem+json",
+        response.headers()["Content-Type"]
+    );
+    let problem = response.json::<Value>().await.unwrap();
+    assert_eq!("/problems/invalid-request", problem["type"]);
+    assert_eq!("/pokemon", problem["instance"]);
+}
@@ -1,9 +1,14 @@
+use std::time::Duration;
+
+use serde_json::{json, Value};
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, ResponseTemplate};
 
+use hexagonal_pokedex::TranslationMode;
+
 use crate::api::helpers::{
-    execute_get_request, random_pokemon_name, spawn_app, valid_translation_response,
-    PokeApiResponseBuilder,
+    execute_get_request, pokemon_query, random_pokemon_name, spawn_app, spawn_app_with,
+    valid_translation_response, PokeApiResponseBuilder,
 };
 
 #[actix_rt::test]
@@ -37,6 +42,7 @@ async fn pokemon_translated_returns_404_with_non_existent_pokemon() {
     let test_app = spawn_app().await;
 
     Mock::given(method("POST"))
+        .and(pokemon_query())
         .respond_with(
             ResponseTemplate::new(200)
                 .set_body_json(PokeApiResponseBuilder::new().without_pokemon().finish()),
@@ -207,4 +213,297 @@ async fn pokemon_translated_cache_translation() {
     .await;
 
     assert_eq!(200, response.status());
+    assert_eq!(
+        json!({"applied": true, "source": "remote"}),
+        response.json::<Value>().await.unwrap()["translation"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_returns_original_description_when_rate_limited() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429))
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+    let body = response.json::<Value>().await.unwrap();
+    assert_eq!("any_description", body["description"]);
+    assert_eq!(
+        json!({"applied": false, "reason": "rate_limited"}),
+        body["translation"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_returns_original_description_when_translator_fails() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+    let body = response.json::<Value>().await.unwrap();
+    assert_eq!("any_description", body["description"]);
+    assert_eq!(
+        json!({"applied": false, "reason": "translator_error"}),
+        body["translation"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_uses_the_requested_style() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                PokeApiResponseBuilder::new()
+                    .with_legendary_status(true)
+                    .finish(),
+            ),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("pirate.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(valid_translation_response()))
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("yoda.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(valid_translation_response()))
+        .expect(0)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon?style=pirate",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_returns_400_with_unknown_style() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon?style=klingon",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(400, response.status());
+    assert_eq!(
+        "/problems/unknown-style",
+        response.json::<Value>().await.unwrap()["type"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_translates_locally_without_calling_funtranslation() {
+    let test_app = spawn_app_with(|settings| {
+        settings
+            .translation
+            .modes
+            .insert("yoda".to_string(), TranslationMode::Local);
+    })
+    .await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(valid_translation_response()))
+        .expect(0)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+    let body = response.json::<Value>().await.unwrap();
+    assert_eq!(
+        json!({"applied": true, "source": "local"}),
+        body["translation"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_falls_back_to_local_translation_when_rate_limited() {
+    let test_app = spawn_app_with(|settings| {
+        settings
+            .translation
+            .modes
+            .insert("yoda".to_string(), TranslationMode::Fallback);
+    })
+    .await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("yoda.json"))
+        .respond_with(ResponseTemplate::new(429))
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+    let body = response.json::<Value>().await.unwrap();
+    assert_eq!(
+        json!({"applied": true, "source": "local_fallback"}),
+        body["translation"]
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_coalesces_concurrent_translations_of_the_same_pokemon() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(PokeApiResponseBuilder::new().finish())
+                .set_delay(Duration::from_millis(200)),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(valid_translation_response())
+                .set_delay(Duration::from_millis(200)),
+        )
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let url = format!("{}/pokemon/translated/any_pokemon", test_app.address);
+    let responses = futures::future::join_all((0..3).map(|_| execute_get_request(&url))).await;
+    for response in responses {
+        assert_eq!(200, response.status());
+        let body = response.json::<Value>().await.unwrap();
+        assert_eq!(
+            json!({"applied": true, "source": "remote"}),
+            body["translation"]
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_returns_its_own_cache_control_and_varies_on_language() {
+    let test_app = spawn_app_with(|config| config.http_cache.translated_max_age_seconds = 60).await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(valid_translation_response()))
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+
+    assert_eq!(200, response.status());
+    assert_eq!("public, max-age=60", response.headers()["Cache-Control"]);
+    assert_eq!("Accept-Language", response.headers()["Vary"]);
+    assert!(response.headers().contains_key("ETag"));
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_does_not_let_the_untranslated_description_be_stored() {
+    let test_app = spawn_app_with(|config| config.http_cache.translated_max_age_seconds = 60).await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .mount(&test_app.pokeapi_server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+
+    assert_eq!(200, response.status());
+    assert_eq!("no-store", response.headers()["Cache-Control"]);
+    assert_eq!(
+        false,
+        response.json::<Value>().await.unwrap()["translation"]["applied"]
+    );
 }
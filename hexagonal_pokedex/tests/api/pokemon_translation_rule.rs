@@ -0,0 +1,150 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+use hexagonal_pokedex::{load_configuration, PokedexApp};
+
+use crate::api::helpers::{
+    execute_get_request, spawn_app, spawn_app_with, valid_translation_response,
+    PokeApiResponseBuilder,
+};
+
+#[actix_rt::test]
+async fn pokemon_translation_rule_explains_the_matching_rule() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                PokeApiResponseBuilder::new()
+                    .with_habitat("cave")
+                    .with_legendary_status(false)
+                    .finish(),
+            ),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/any_pokemon/translation-rule",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+    assert_eq!(
+        json!({
+            "rule": { "name": "cave", "when": { "habitat": "cave" }, "style": "yoda" },
+            "style": "yoda"
+        }),
+        response.json::<Value>().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_translation_rule_reports_the_default_style_without_matching_rules() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                PokeApiResponseBuilder::new()
+                    .with_habitat("forest")
+                    .with_legendary_status(false)
+                    .finish(),
+            ),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/any_pokemon/translation-rule",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+    assert_eq!(
+        json!({ "rule": null, "style": "shakespeare" }),
+        response.json::<Value>().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn pokemon_translated_follows_the_configured_rules() {
+    let test_app = spawn_app_with(|config| {
+        config.translation.rules.clear();
+        config.translation.default_style = "pirate".to_string();
+    })
+    .await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                PokeApiResponseBuilder::new()
+                    .with_legendary_status(true)
+                    .finish(),
+            ),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("pirate.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(valid_translation_response()))
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+}
+
+#[actix_rt::test]
+async fn pokedex_does_not_start_with_rules_selecting_unregistered_styles() {
+    let mut config = load_configuration().unwrap();
+    config.application.port = 0;
+    config.translation.default_style = "klingon".to_string();
+
+    assert!(PokedexApp::new(config).await.is_err());
+}
+
+#[actix_rt::test]
+async fn pokemon_translation_rule_retrieves_the_pokemon_in_the_requested_language() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "variables": { "languages": ["fr", "en"] }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(PokeApiResponseBuilder::new().with_language("fr").finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/any_pokemon/translation-rule?lang=fr",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/any_pokemon/translation-rule?lang=",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(400, response.status());
+    assert_eq!(
+        "/problems/invalid-language",
+        response.json::<Value>().await.unwrap()["type"]
+    );
+}
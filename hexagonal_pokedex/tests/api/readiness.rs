@@ -0,0 +1,124 @@
+use serde_json::{json, Value};
+use wiremock::matchers::method;
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::api::helpers::{
+    execute_get_request, spawn_app, spawn_app_with, valid_translation_response,
+    PokeApiResponseBuilder,
+};
+
+#[actix_rt::test]
+async fn readiness_reports_available_cache() {
+    let test_app = spawn_app().await;
+
+    let response = execute_get_request(&format!("{}/readiness", test_app.address)).await;
+    assert_eq!(200, response.status());
+    assert_eq!(
+        json!({
+            "cache": "available",
+            "translationQuota": {"limit": 5, "remaining": 5},
+            "circuits": {"funtranslation_api": "closed", "poke_api": "closed"}
+        }),
+        response.json::<Value>().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn app_starts_and_translates_without_redis() {
+    let test_app = spawn_app_with(|config| {
+        config.redis_cache.url = "redis://127.0.0.1:1/".parse().unwrap();
+    })
+    .await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .expect(1)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(valid_translation_response()))
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    let response = execute_get_request(&format!(
+        "{}/pokemon/translated/any_pokemon",
+        test_app.address
+    ))
+    .await;
+    assert_eq!(200, response.status());
+
+    let response = execute_get_request(&format!("{}/readiness", test_app.address)).await;
+    assert_eq!(200, response.status());
+    assert_eq!(
+        json!({
+            "cache": "unavailable",
+            "translationQuota": {"limit": 5, "remaining": 4},
+            "circuits": {"funtranslation_api": "closed", "poke_api": "closed"}
+        }),
+        response.json::<Value>().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn readiness_reports_the_exhausted_translation_quota() {
+    let test_app = spawn_app().await;
+
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(PokeApiResponseBuilder::new().finish()),
+        )
+        .expect(2)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+        .expect(1)
+        .mount(&test_app.translated_server)
+        .await;
+
+    for _ in 0..2 {
+        let response = execute_get_request(&format!(
+            "{}/pokemon/translated/any_pokemon",
+            test_app.address
+        ))
+        .await;
+        assert_eq!(200, response.status());
+    }
+
+    let response = execute_get_request(&format!("{}/readiness", test_app.address)).await;
+    assert_eq!(
+        json!({"limit": 5, "remaining": 0}),
+        response.json::<Value>().await.unwrap()["translationQuota"]
+    );
+}
+
+#[actix_rt::test]
+async fn readiness_reports_the_open_circuit_of_a_failing_upstream() {
+    let test_app = spawn_app_with(|config| {
+        config.circuit_breaker.failure_threshold = 2;
+    })
+    .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(2)
+        .mount(&test_app.pokeapi_server)
+        .await;
+
+    for _ in 0..3 {
+        let response =
+            execute_get_request(&format!("{}/pokemon/any_pokemon", test_app.address)).await;
+        assert_eq!(502, response.status());
+    }
+
+    let response = execute_get_request(&format!("{}/readiness", test_app.address)).await;
+    assert_eq!(
+        json!({"funtranslation_api": "closed", "poke_api": "open"}),
+        response.json::<Value>().await.unwrap()["circuits"]
+    );
+}
@@ -0,0 +1,188 @@
+//! The traces exported over OTLP, in a binary of their own
+//! since the tracing subscriber is global and the spans are exported once, at its shutdown.
+
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+use opentelemetry_otlp::proto::collector::trace::v1::trace_service_server::{
+    TraceService, TraceServiceServer,
+};
+use opentelemetry_otlp::proto::collector::trace::v1::{
+    ExportTraceServiceRequest, ExportTraceServiceResponse,
+};
+use opentelemetry_otlp::proto::common::v1::any_value::Value;
+use opentelemetry_otlp::proto::trace::v1::Span;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use hexagonal_pokedex::{load_configuration, setup_tracing, shutdown_tracing, PokedexApp};
+
+/// Stand-in of the OpenTelemetry collector keeping the exported spans.
+#[derive(Clone, Default)]
+struct Collector {
+    spans: Arc<Mutex<Vec<Span>>>,
+}
+
+#[tonic::async_trait]
+impl TraceService for Collector {
+    async fn export(
+        &self,
+        request: tonic::Request<ExportTraceServiceRequest>,
+    ) -> Result<tonic::Response<ExportTraceServiceResponse>, tonic::Status> {
+        let spans = request
+            .into_inner()
+            .resource_spans
+            .into_iter()
+            .flat_map(|resource_spans| resource_spans.instrumentation_library_spans)
+            .flat_map(|library_spans| library_spans.spans);
+        self.spans.lock().unwrap().extend(spans);
+        Ok(tonic::Response::new(ExportTraceServiceResponse {}))
+    }
+}
+
+#[actix_rt::test]
+async fn the_spans_are_exported_with_their_fields_in_the_trace_of_the_caller() {
+    let collector = Collector::default();
+    let collector_address = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    tokio::spawn(
+        tonic::transport::Server::builder()
+            .add_service(TraceServiceServer::new(collector.clone()))
+            .serve(collector_address),
+    );
+    setup_tracing(
+        "test".into(),
+        "info".into(),
+        Some(&format!("http://{}", collector_address).parse().unwrap()),
+    )
+    .unwrap();
+
+    // random, since the translations cache is shared with the other test binaries
+    let pokemon_name: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(8)
+        .map(|c| char::from(c).to_ascii_lowercase())
+        .collect();
+    let pokeapi_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "variables": { "names": [pokemon_name] } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "info": [{
+                "id": 150,
+                "name": pokemon_name,
+                "descriptions": [{ "flavor_text": "any_description", "language": { "name": "en" } }],
+                "is_legendary": false,
+                "is_mythical": false,
+                "varieties": []
+            }] }
+        })))
+        .mount(&pokeapi_server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "info": [] }
+        })))
+        .mount(&pokeapi_server)
+        .await;
+    let funtranslation_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": { "total": 1 },
+            "contents": { "translated": "any_text_translated", "text": "any_text" }
+        })))
+        .expect(1)
+        .mount(&funtranslation_server)
+        .await;
+    let mut config = load_configuration().unwrap();
+    config.application.port = 0;
+    config.poke_api.url = pokeapi_server.uri().parse().unwrap();
+    config.funtranslation_api.url = funtranslation_server.uri().parse().unwrap();
+    config.pokemon_cache.ttl_seconds = 0;
+    let app = PokedexApp::new(config).await.unwrap();
+    tokio::spawn(app.server.unwrap());
+
+    let trace_id = "4bf92f3577b34da6a3ce929d0e0e4736";
+    let response = reqwest::Client::new()
+        .get(format!("http://127.0.0.1:{}/pokemon/25", app.port))
+        .header(
+            "traceparent",
+            format!("00-{}-00f067aa0ba902b7-01", trace_id),
+        )
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(404, response.status());
+    // translated, then served from the translations cache
+    for _ in 0..2 {
+        let response = reqwest::get(format!(
+            "http://127.0.0.1:{}/pokemon/translated/{}",
+            app.port, pokemon_name
+        ))
+        .await
+        .unwrap();
+        assert_eq!(200, response.status());
+    }
+
+    // the flush blocks until the exporter task, running on this thread, sends the spans
+    tokio::task::spawn_blocking(shutdown_tracing).await.unwrap();
+
+    let spans = collector.spans.lock().unwrap();
+    let query = spans
+        .iter()
+        .find(|span| span.name == "Querying PokeAPI")
+        .expect("the PokeAPI query is exported");
+    assert_eq!(trace_id, hex(&query.trace_id));
+
+    assert_eq!(
+        vec!["miss", "hit"],
+        attributes(
+            &spans,
+            "Translating the description through the cache",
+            "cache"
+        )
+    );
+    assert_eq!(
+        vec!["remote"],
+        attributes(&spans, "Routing the translation", "translator")
+    );
+    let translation_lookups: Vec<String> = sorted(&spans, "Getting from the cache")
+        .into_iter()
+        .filter(|span| attribute(span, "key").is_some_and(|key| key.starts_with("translation:")))
+        .filter_map(|span| attribute(span, "outcome"))
+        .collect();
+    assert_eq!(vec!["miss", "hit"], translation_lookups);
+}
+
+/// The values of the `key` attribute of the spans named `name`, in their start order.
+fn attributes(spans: &[Span], name: &str, key: &str) -> Vec<String> {
+    sorted(spans, name)
+        .into_iter()
+        .filter_map(|span| attribute(span, key))
+        .collect()
+}
+
+fn sorted<'a>(spans: &'a [Span], name: &str) -> Vec<&'a Span> {
+    let mut spans: Vec<&Span> = spans.iter().filter(|span| span.name == name).collect();
+    spans.sort_by_key(|span| span.start_time_unix_nano);
+    spans
+}
+
+fn attribute(span: &Span, key: &str) -> Option<String> {
+    let attribute = span
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == key)?;
+    match attribute.value.as_ref()?.value.as_ref()? {
+        Value::StringValue(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}